@@ -70,7 +70,7 @@ impl ToTokens for EdgeToken {
             _____struct_marker_ident,
             ..
         } = VariablesModelMacro::new();
-        let table_definitions = match self.get_table_definition_token() {
+        let table_definitions = match self.get_edge_table_definition_token() {
             Ok(table_definitions) => table_definitions,
             Err(err) => return tokens.extend(err.write_errors()),
         };
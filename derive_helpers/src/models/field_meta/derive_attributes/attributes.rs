@@ -1,15 +1,52 @@
 use super::ExtractorResult;
-use crate::models::MyFieldReceiver;
+use crate::models::{AttributeValue, MyFieldReceiver};
+use darling::{ast::Data, util::Ignored, FromField};
 use syn::{spanned::Spanned, Attribute};
 
+/// Reads `default` from `#[orm(...)]` only i.e the database default of the field.
+#[derive(FromField)]
+#[darling(attributes(orm), allow_unknown_fields)]
+struct OrmDefault {
+    #[darling(default)]
+    default: Option<AttributeValue>,
+}
+
+/// Reads `default` from `#[serde(...)]` only, in its word or function path form.
+#[derive(FromField)]
+#[darling(attributes(serde), allow_unknown_fields)]
+struct SerdeDefault {
+    #[darling(default)]
+    default: Option<Ignored>,
+}
+
 impl MyFieldReceiver {
     pub fn attributes(&self) -> &Vec<Attribute> {
         &self.attrs
     }
 
+    /// Parses the fields of a struct. darling reads the `orm` and `serde` attributes together,
+    /// so `default`, which means something different in each, is read separately from each field.
+    pub fn parse_fields(data: &syn::Data) -> darling::Result<Data<Ignored, Self>> {
+        let mut parsed = Data::<Ignored, Self>::try_from(data)?;
+
+        if let (Data::Struct(parsed), syn::Data::Struct(data)) = (&mut parsed, data) {
+            for (receiver, field) in parsed.fields.iter_mut().zip(data.fields.iter()) {
+                receiver.read_defaults(field)?;
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn read_defaults(&mut self, field: &syn::Field) -> darling::Result<()> {
+        self.db_default = OrmDefault::from_field(field)?.default;
+        self.serde_default = SerdeDefault::from_field(field)?.default.is_some();
+        Ok(())
+    }
+
     pub fn validate_attributes(&self) -> ExtractorResult<()> {
         if self.relate.is_some() {
-            let has_required_attributes = self.skip_serializing && self.default;
+            let has_required_attributes = self.skip_serializing && self.serde_default;
 
             if !has_required_attributes {
                 let field_name = self.ident()?.to_string();
@@ -87,12 +87,26 @@ pub struct MyFieldReceiver {
     #[darling(default)]
     pub(crate) item_assert: Option<AttributeItemAssert>,
 
+    #[darling(default)]
+    pub(crate) readonly: bool,
+
+    #[darling(default)]
+    pub(crate) flexible: bool,
+
     // Serde attributes
     #[darling(default)]
     pub(crate) skip_serializing: bool,
 
+    // `default` is serde's in `#[serde(default)]` and the database default in
+    // `#[orm(default = ...)]`. Both are read in `MyFieldReceiver::read_defaults`.
     #[darling(default)]
-    pub(crate) default: bool,
+    default: ::darling::util::Ignored,
+
+    #[darling(skip)]
+    pub(crate) serde_default: bool,
+
+    #[darling(skip)]
+    pub(crate) db_default: Option<AttributeValue>,
 
     #[darling(default)]
     pub(crate) skip: bool,
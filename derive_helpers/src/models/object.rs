@@ -19,6 +19,7 @@ pub struct ObjectToken {
     pub(crate) generics: StructGenerics,
     /// Receives the body of the struct or enum. We don't care about
     /// struct fields because we previously told darling we only accept structs.
+    #[darling(with = MyFieldReceiver::parse_fields)]
     pub data: ast::Data<util::Ignored, MyFieldReceiver>,
 
     #[darling(default)]
@@ -43,6 +43,7 @@ pub struct TableDeriveAttributes {
     pub(crate) generics: StructGenerics,
     /// Receives the body of the struct or enum. We don't care about
     /// struct fields because we previously told darling we only accept structs.
+    #[darling(with = MyFieldReceiver::parse_fields)]
     pub data: Data<util::Ignored, MyFieldReceiver>,
 
     #[darling(default)]
@@ -68,6 +69,12 @@ pub struct TableDeriveAttributes {
 
     pub(crate) permissions: Option<Permissions>,
 
+    #[darling(default)]
+    pub(crate) relation: Option<bool>,
+
+    #[darling(default)]
+    pub(crate) enforced: Option<bool>,
+
     #[darling(default)]
     pub(crate) define: Option<AttributeDefine>,
 }
@@ -91,12 +98,67 @@ impl TableDeriveAttributes {
     }
 
     pub fn get_table_definition_token(&self) -> ExtractorResult<TableDefinitions> {
+        if self.relation.is_some() || self.enforced.is_some() {
+            return Err(syn::Error::new_spanned(
+                self.ident.clone(),
+                "`relation` and `enforced` can only be used on an Edge since only relation tables have records on both ends.",
+            )
+            .into());
+        }
+
+        self.table_definition_token(vec![])
+    }
+
+    /// Same as `get_table_definition_token` but with `relation`, also restricts the table
+    /// to relations between the `In` and `Out` nodes of the edge, i.e `TYPE RELATION IN .. OUT ..`.
+    pub fn get_edge_table_definition_token(&self) -> ExtractorResult<TableDefinitions> {
+        if self.relation != Some(true) {
+            if self.enforced.is_some() {
+                return Err(syn::Error::new_spanned(
+                    self.ident.clone(),
+                    "`enforced` only applies to the relation type of the table. Use it together with `relation`.",
+                )
+                .into());
+            }
+
+            return self.table_definition_token(vec![]);
+        }
+
+        if !cfg!(feature = "surrealdb-v2") {
+            return Err(syn::Error::new_spanned(
+                self.ident.clone(),
+                "`relation` requires SurrealDB 2.0. Enable the `surrealdb-v2` feature to use it",
+            )
+            .into());
+        }
+
+        let crate_name = get_crate_name(false);
+        let mut relation_methods = vec![quote!(
+            .type_relation(
+                <In as #crate_name::Model>::table(),
+                <Out as #crate_name::Model>::table()
+            )
+        )];
+
+        if self.enforced == Some(true) {
+            relation_methods.push(quote!(.enforced()));
+        }
+
+        self.table_definition_token(relation_methods)
+    }
+
+    fn table_definition_token(
+        &self,
+        extra_methods: Vec<TokenStream>,
+    ) -> ExtractorResult<TableDefinitions> {
         let TableDeriveAttributes {
             ref drop,
             ref flexible,
             ref schemafull,
             ref as_,
             ref permissions,
+            ref relation,
+            ref enforced,
             ref define,
             ..
         } = *self;
@@ -108,7 +170,9 @@ impl TableDeriveAttributes {
                 || as_.is_some()
                 || schemafull.is_some()
                 || flexible.is_some()
-                || permissions.is_some())
+                || permissions.is_some()
+                || relation.is_some()
+                || enforced.is_some())
         {
             return Err(
                 syn::Error::new_spanned(
@@ -118,7 +182,9 @@ impl TableDeriveAttributes {
                             flexible,
                             as,
                             schemafull,
-                            permissions",
+                            permissions,
+                            relation,
+                            enforced",
                 )
                 .into(),
             );
@@ -147,6 +213,8 @@ impl TableDeriveAttributes {
             define_table_methods.push(quote!(.schemafull()))
         }
 
+        define_table_methods.extend(extra_methods);
+
         if let Some(permissions) = permissions {
             define_table_methods.push(permissions.to_token_stream());
         }
@@ -162,3 +230,75 @@ impl TableDeriveAttributes {
             .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use darling::FromDeriveInput;
+    use quote::{quote, ToTokens};
+
+    use crate::models::EdgeToken;
+
+    fn edge_tokens(input: syn::DeriveInput) -> String {
+        EdgeToken::from_derive_input(&input)
+            .unwrap()
+            .to_token_stream()
+            .to_string()
+    }
+
+    #[test]
+    fn test_edge_table_relation_type_is_opt_in() {
+        let tokens = edge_tokens(syn::parse_quote! {
+            #[orm(table = writes)]
+            struct Writes<In: Node, Out: Node> {
+                id: SurrealSimpleId<Self>,
+                #[serde(rename = "in")]
+                #[orm(link_one = In)]
+                in_: LinkOne<In>,
+                #[orm(link_one = Out)]
+                out: LinkOne<Out>,
+            }
+        });
+
+        assert!(!tokens.contains("type_relation"));
+        assert!(!tokens.contains("enforced"));
+    }
+
+    #[test]
+    fn test_edge_table_relation_type() {
+        let tokens = edge_tokens(syn::parse_quote! {
+            #[orm(table = writes, relation, enforced)]
+            struct Writes<In: Node, Out: Node> {
+                id: SurrealSimpleId<Self>,
+                #[serde(rename = "in")]
+                #[orm(link_one = In)]
+                in_: LinkOne<In>,
+                #[orm(link_one = Out)]
+                out: LinkOne<Out>,
+            }
+        });
+
+        if cfg!(feature = "surrealdb-v2") {
+            assert!(tokens.contains("type_relation"));
+            assert!(tokens.contains(&quote!(.enforced()).to_string()));
+        } else {
+            assert!(tokens.contains("`relation` requires SurrealDB 2.0"));
+        }
+    }
+
+    #[test]
+    fn test_edge_table_enforced_requires_relation() {
+        let tokens = edge_tokens(syn::parse_quote! {
+            #[orm(table = writes, enforced)]
+            struct Writes<In: Node, Out: Node> {
+                id: SurrealSimpleId<Self>,
+                #[serde(rename = "in")]
+                #[orm(link_one = In)]
+                in_: LinkOne<In>,
+                #[orm(link_one = Out)]
+                out: LinkOne<Out>,
+            }
+        });
+
+        assert!(tokens.contains("Use it together with `relation`"));
+    }
+}
@@ -53,6 +53,18 @@ impl Codegen<'_> {
             define_array_field_item_methods.push(quote!(.assert(#item_assert)));
         }
 
+        if field_receiver.flexible {
+            define_field_methods.push(quote!(.flexible()));
+        }
+
+        if let Some(default) = field_receiver.db_default.as_ref() {
+            define_field_methods.push(quote!(.default(#default)));
+        }
+
+        if field_receiver.readonly {
+            define_field_methods.push(quote!(.readonly()));
+        }
+
         if let Some(value) = field_receiver.value.as_ref() {
             define_field_methods.push(quote!(.value(#value)));
         }
@@ -99,13 +111,20 @@ impl Codegen<'_> {
 
         match field_type {
             Some(field_type) => {
-                let static_assertion = field_receiver
+                let field_type = field_type.into_inner();
+                let value_assertion = field_receiver
                     .value
                     .as_ref()
                     .map_or(StaticAssertionToken::default(), |v| {
-                        v.get_default_value_static_assertion(field_type.into_inner())
+                        v.get_default_value_static_assertion(field_type.clone())
                     });
-                Ok(static_assertion)
+                let default_assertion = field_receiver
+                    .db_default
+                    .as_ref()
+                    .map_or(StaticAssertionToken::default(), |v| {
+                        v.get_default_value_static_assertion(field_type)
+                    });
+                Ok(quote!(#value_assertion #default_assertion).into())
             }
             None => Ok(StaticAssertionToken::default()),
         }
@@ -120,6 +139,9 @@ impl Codegen<'_> {
             permissions,
             item_assert,
             relate,
+            readonly,
+            flexible,
+            db_default,
             ..
         } = field_receiver;
         let ident = field_receiver.ident()?;
@@ -130,7 +152,10 @@ impl Codegen<'_> {
             && (assert_.is_some()
                 || value.is_some()
                 || permissions.is_some()
-                || item_assert.is_some())
+                || item_assert.is_some()
+                || db_default.is_some()
+                || *readonly
+                || *flexible)
         {
             return Err(
                 syn::Error::new_spanned(
@@ -139,7 +164,10 @@ impl Codegen<'_> {
     assert,
     value,
     permissions,
-    item_assert"#).into());
+    item_assert,
+    default,
+    readonly,
+    flexible"#).into());
         }
 
         if relate.is_some()
@@ -147,7 +175,10 @@ impl Codegen<'_> {
                 || assert_.is_some()
                 || value.is_some()
                 || permissions.is_some()
-                || item_assert.is_some())
+                || item_assert.is_some()
+                || db_default.is_some()
+                || *readonly
+                || *flexible)
         {
             return Err(syn::Error::new_spanned(
                 ident,
@@ -156,7 +187,18 @@ impl Codegen<'_> {
     assert,
     value,
     permissions,
-    item_assert"#,
+    item_assert,
+    default,
+    readonly,
+    flexible"#,
+            )
+            .into());
+        }
+
+        if *readonly && !cfg!(feature = "surrealdb-v2") {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`readonly` requires SurrealDB 2.0. Enable the `surrealdb-v2` feature to use it",
             )
             .into());
         }
@@ -164,3 +206,59 @@ impl Codegen<'_> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use darling::FromDeriveInput;
+    use quote::{quote, ToTokens};
+
+    use crate::models::NodeToken;
+
+    fn node_tokens(input: syn::DeriveInput) -> String {
+        NodeToken::from_derive_input(&input)
+            .unwrap()
+            .to_token_stream()
+            .to_string()
+    }
+
+    #[test]
+    fn test_flexible_and_default_field_attributes() {
+        let tokens = node_tokens(syn::parse_quote! {
+            #[orm(table = user)]
+            struct User {
+                id: SurrealSimpleId<Self>,
+                #[orm(flexible, ty = "object")]
+                settings: Settings,
+                #[serde(default)]
+                #[orm(default = "pending")]
+                status: String,
+                #[serde(default = "default_age")]
+                age: u8,
+            }
+        });
+
+        assert!(tokens.contains(&quote!(.flexible()).to_string()));
+        assert!(tokens.contains(&quote!(.default("pending")).to_string()));
+        // `#[serde(default = ...)]` is not a database default.
+        assert!(!tokens.contains("default_age"));
+        assert!(!tokens.contains(&quote!(.readonly()).to_string()));
+    }
+
+    #[test]
+    fn test_readonly_field_attribute() {
+        let tokens = node_tokens(syn::parse_quote! {
+            #[orm(table = user)]
+            struct User {
+                id: SurrealSimpleId<Self>,
+                #[orm(readonly)]
+                created_at: chrono::DateTime<chrono::Utc>,
+            }
+        });
+
+        if cfg!(feature = "surrealdb-v2") {
+            assert!(tokens.contains(&quote!(.readonly()).to_string()));
+        } else {
+            assert!(tokens.contains("`readonly` requires SurrealDB 2.0"));
+        }
+    }
+}
@@ -177,9 +177,8 @@ impl TestConfig {
     pub async fn assert_db_resources_state(&mut self) -> DbMigrationSchemaState {
         let db_info = info_for()
             .database()
-            .get_data::<DbInfo>(self.migrator.db().clone())
+            .get_info::<DbInfo>(self.migrator.db().clone())
             .await
-            .unwrap()
             .expect("Failed to get db info");
         let migrations = Migration::get_all_desc(self.migrator.db().clone()).await;
         let db_miration_schema_state = DbMigrationSchemaState::new(db_info.clone(), migrations);
@@ -0,0 +1,91 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use migrator_tests::{current_function, TestConfig};
+use surreal_models::migrations::Resources;
+use surreal_orm::migrator::{
+    DefinePolicy, DefineStatementRaw, Init, MigrationError, MockPrompter, Mode,
+};
+use tempfile::tempdir;
+use test_case::test_case;
+
+async fn init_migrations(mode: Mode, policy: DefinePolicy, run: bool) -> String {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    conf.migrator.set_define_policy(policy);
+
+    conf.run_init(
+        Init::builder()
+            .reversible(false)
+            .name("migration init".into())
+            .run(run)
+            .build(),
+        Resources,
+        MockPrompter::default(),
+    )
+    .await;
+
+    conf.read_migrations_from_dir_sorted_asc()
+        .iter()
+        .map(|filename| {
+            std::fs::read_to_string(migration_dir.join(filename.to_string()))
+                .expect("Failed to read migration file")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test_case(DefinePolicy::Plain, "DEFINE TABLE user", "DEFINE TABLE user"; "Plain")]
+#[test_case(DefinePolicy::IfNotExists, "DEFINE TABLE IF NOT EXISTS user", "DEFINE TABLE user"; "IfNotExists")]
+#[test_case(DefinePolicy::Overwrite, "DEFINE TABLE OVERWRITE user", "DEFINE TABLE OVERWRITE user"; "Overwrite")]
+fn test_define_policy_modifiers(policy: DefinePolicy, on_create: &str, on_update: &str) {
+    let definition = DefineStatementRaw::from("DEFINE TABLE user".to_string());
+
+    assert_eq!(policy.on_create(definition.clone()).trim(), on_create);
+    assert_eq!(policy.on_update(definition).trim(), on_update);
+}
+
+#[test_case(Mode::Strict; "Strict")]
+#[test_case(Mode::Lax; "Lax")]
+#[tokio::test]
+async fn test_plain_define_policy_migrations_run(mode: Mode) {
+    let queries = init_migrations(mode, DefinePolicy::Plain, true).await;
+
+    assert!(queries.contains("DEFINE TABLE planet SCHEMAFULL"));
+    assert!(!queries.contains("IF NOT EXISTS"));
+    assert!(!queries.contains("OVERWRITE"));
+}
+
+#[cfg(not(feature = "surrealdb-v2"))]
+#[test_case(DefinePolicy::IfNotExists; "IfNotExists")]
+#[test_case(DefinePolicy::Overwrite; "Overwrite")]
+fn test_define_policy_requires_surrealdb_v2(policy: DefinePolicy) {
+    assert!(DefinePolicy::Plain.ensure_supported().is_ok());
+    assert!(matches!(
+        policy.ensure_supported(),
+        Err(MigrationError::UnsupportedDefinePolicy(_))
+    ));
+}
+
+#[cfg(not(feature = "surrealdb-v2"))]
+#[test_case(DefinePolicy::IfNotExists; "IfNotExists")]
+#[test_case(DefinePolicy::Overwrite; "Overwrite")]
+#[tokio::test]
+#[should_panic(expected = "requires SurrealDB 2.0")]
+async fn test_cannot_generate_migrations_with_surrealdb_v2_define_policy(policy: DefinePolicy) {
+    init_migrations(Mode::Strict, policy, false).await;
+}
+
+#[cfg(feature = "surrealdb-v2")]
+#[test_case(DefinePolicy::IfNotExists, "DEFINE TABLE IF NOT EXISTS planet SCHEMAFULL"; "IfNotExists")]
+#[test_case(DefinePolicy::Overwrite, "DEFINE TABLE OVERWRITE planet SCHEMAFULL"; "Overwrite")]
+#[tokio::test]
+async fn test_define_policy_migrations(policy: DefinePolicy, planet_definition: &str) {
+    let queries = init_migrations(Mode::Strict, policy, false).await;
+
+    assert!(queries.contains(planet_definition));
+}
@@ -31,7 +31,9 @@ use clap::{ArgAction, Parser};
 use surreal_query_builder::DbResources;
 
 pub use self::config::DatabaseConnection;
-use crate::{MigrationConfig, MockPrompter, Mode, Prompter, RealPrompter, RenameOrDelete};
+use crate::{
+    DefinePolicy, MigrationConfig, MockPrompter, Mode, Prompter, RealPrompter, RenameOrDelete,
+};
 
 /// Surreal ORM CLI
 #[derive(Parser, Debug, Clone, TypedBuilder)]
@@ -67,6 +69,17 @@ pub struct Migrator {
     )]
     pub(crate) mode: Mode,
 
+    #[arg(
+        value_enum,
+        global = true,
+        long,
+        help = "Modifier to use for the DEFINE statements of generated migrations. \
+            if-not-exists only defines new resources that do not exist yet, overwrite replaces existing ones.",
+        default_value_t = DefinePolicy::Plain,
+    )]
+    #[builder(default)]
+    pub(crate) define_policy: DefinePolicy,
+
     #[command(flatten)]
     pub(crate) db_connection: DatabaseConnection,
 }
@@ -85,10 +98,16 @@ impl Migrator {
         self
     }
 
+    pub fn set_define_policy(&mut self, define_policy: DefinePolicy) -> &mut Self {
+        self.define_policy = define_policy;
+        self
+    }
+
     pub fn file_manager(&self) -> MigrationConfig {
         let fm_init = MigrationConfig::builder()
             .custom_path(self.dir.clone())
-            .mode(self.mode);
+            .mode(self.mode)
+            .define_policy(self.define_policy);
 
         fm_init.build()
     }
@@ -126,7 +126,7 @@ impl Up {
             }
         };
 
-        let info = info_for().database().get_data::<DbInfo>(db.clone()).await;
+        let info = info_for().database().get_info::<DbInfo>(db.clone()).await;
         if let Err(ref e) = info {
             log::error!("Failed to get db info: {e}");
         }
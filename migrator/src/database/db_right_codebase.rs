@@ -99,6 +99,8 @@ impl RightDatabase {
         let queries_joined = [
             db_resources.tokens(),
             db_resources.scopes(),
+            #[cfg(feature = "surrealdb-v2")]
+            db_resources.accesses(),
             db_resources.analyzers(),
             db_resources.params(),
             db_resources.functions(),
@@ -6,7 +6,10 @@
 
 use std::{collections::BTreeMap, ops::Deref};
 
-use surreal_query_builder::{statements::info_for, *};
+use surreal_query_builder::{
+    statements::{info_for, DatabaseInfo, TableInfo},
+    *,
+};
 use surrealdb::{
     engine::local::{Db, Mem},
     Surreal,
@@ -71,19 +74,17 @@ impl MigratorDatabase {
     pub async fn get_db_info(&self) -> MigrationResult<DbInfo> {
         let info = info_for()
             .database()
-            .get_data::<DbInfo>(self.db())
-            .await?
-            .expect("Database not found");
-        Ok(info)
+            .get_info::<DatabaseInfo>(self.db())
+            .await?;
+        Ok(info.into())
     }
 
     pub async fn get_table_info(&self, table: String) -> MigrationResult<TableResourcesData> {
         let info = info_for()
             .table(table)
-            .get_data::<TableResourcesData>(self.db())
-            .await?
-            .expect("Table not found");
-        Ok(info)
+            .get_info::<TableInfo>(self.db())
+            .await?;
+        Ok(info.into())
     }
 
     pub async fn get_all_resources(&self) -> MigrationResult<FullDbInfo> {
@@ -113,6 +114,7 @@ impl MigratorDatabase {
         prompter: impl Prompter,
     ) -> MigrationResult<()> {
         let migration_basename = migration_basename.normalize_ensure();
+        file_manager.define_policy().ensure_supported()?;
 
         // Left = migration directory
         // Right = codebase
@@ -138,6 +140,7 @@ impl MigratorDatabase {
             left_resources: &left.resources().await,
             right_resources: &right.resources().await,
             prompter: &prompter,
+            define_policy: file_manager.define_policy(),
         };
 
         let tables = init.new_tables(&codebase_resources).queries()?;
@@ -145,6 +148,10 @@ impl MigratorDatabase {
         let params = init.new_params().queries()?.intersperse_new_lines();
         let functions = init.new_functions().queries()?.intersperse_new_lines();
         let scopes = init.new_scopes().queries()?.intersperse_new_lines();
+        #[cfg(feature = "surrealdb-v2")]
+        let accesses = init.new_accesses().queries()?.intersperse_new_lines();
+        #[cfg(not(feature = "surrealdb-v2"))]
+        let accesses = Queries::default();
         let tokens = init.new_tokens().queries()?.intersperse_new_lines();
         let users = init.new_users().queries()?.intersperse_new_lines();
         let migration_reset =
@@ -157,6 +164,7 @@ impl MigratorDatabase {
             params,
             functions,
             scopes,
+            accesses,
             tokens,
             users,
         ];
@@ -34,6 +34,9 @@ pub struct MigrationConfig {
     // After init => Some(MigrationFlag)
     #[builder(default)]
     pub migration_flag: Option<MigrationFlag>,
+    /// Modifier policy for the DEFINE statements of generated migrations
+    #[builder(default)]
+    pub define_policy: DefinePolicy,
 }
 
 impl MigrationConfig {
@@ -60,6 +63,15 @@ impl MigrationConfig {
         self.mode
     }
 
+    pub fn set_define_policy(mut self, define_policy: DefinePolicy) -> Self {
+        self.define_policy = define_policy;
+        self
+    }
+
+    pub fn define_policy(&self) -> DefinePolicy {
+        self.define_policy
+    }
+
     pub fn make_strict(mut self) -> Self {
         self.mode = Mode::Strict;
         self
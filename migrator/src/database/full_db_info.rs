@@ -33,6 +33,10 @@ impl FullDbInfo {
         self.all_resources.scopes()
     }
 
+    pub fn accesses(&self) -> Accesses {
+        self.all_resources.accesses()
+    }
+
     pub fn functions(&self) -> Functions {
         self.all_resources.functions()
     }
@@ -12,7 +12,7 @@ use surreal_query_builder::{
     statements::{
         remove_analyzer, remove_database, remove_event, remove_field, remove_function,
         remove_index, remove_model, remove_namespace, remove_param, remove_scope, remove_table,
-        remove_token, remove_user,
+        remove_token, remove_user, DefineMode,
     },
     *,
 };
@@ -177,6 +177,12 @@ impl From<String> for RemoveStatementRaw {
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 pub struct DefineStatementRaw(String);
 
+impl From<String> for DefineStatementRaw {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
 impl From<DefineStatementRaw> for Raw {
     fn from(value: DefineStatementRaw) -> Self {
         Self::new(value.0)
@@ -291,6 +297,25 @@ impl DefineStatementRaw {
     pub fn trim(&self) -> &str {
         self.0.trim()
     }
+
+    /// Sets the `IF NOT EXISTS` or `OVERWRITE` modifier of the definition,
+    /// replacing the one it already has if any.
+    pub fn with_mode(self, mode: DefineMode) -> Self {
+        let definition = self.0.trim().to_string();
+        let mut parts = definition.splitn(3, ' ');
+
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some(define), Some(resource), Some(rest)) => {
+                let rest = rest.trim_start();
+                let rest = rest
+                    .strip_prefix("IF NOT EXISTS ")
+                    .or_else(|| rest.strip_prefix("OVERWRITE "))
+                    .unwrap_or(rest);
+                Self(format!("{define} {resource} {mode} {rest}"))
+            }
+            _ => self,
+        }
+    }
 }
 
 impl Display for DefineStatementRaw {
@@ -298,3 +323,56 @@ impl Display for DefineStatementRaw {
         write!(f, "{};", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_mode_adds_the_mode_after_the_resource_kind() {
+        let definition = DefineStatementRaw::from("DEFINE TABLE user SCHEMAFULL".to_string());
+        assert_eq!(
+            definition
+                .clone()
+                .with_mode(DefineMode::IfNotExists)
+                .to_string(),
+            "DEFINE TABLE IF NOT EXISTS user SCHEMAFULL;"
+        );
+        assert_eq!(
+            definition.with_mode(DefineMode::Overwrite).to_string(),
+            "DEFINE TABLE OVERWRITE user SCHEMAFULL;"
+        );
+
+        let definition =
+            DefineStatementRaw::from("  DEFINE FIELD name ON TABLE user TYPE string ".to_string());
+        assert_eq!(
+            definition.with_mode(DefineMode::Overwrite).to_string(),
+            "DEFINE FIELD OVERWRITE name ON TABLE user TYPE string;"
+        );
+    }
+
+    #[test]
+    fn test_with_mode_replaces_the_existing_mode() {
+        let definition =
+            DefineStatementRaw::from("DEFINE TABLE IF NOT EXISTS user SCHEMAFULL".to_string());
+        assert_eq!(
+            definition.with_mode(DefineMode::Overwrite).to_string(),
+            "DEFINE TABLE OVERWRITE user SCHEMAFULL;"
+        );
+
+        let definition = DefineStatementRaw::from("DEFINE TABLE OVERWRITE user".to_string());
+        assert_eq!(
+            definition.with_mode(DefineMode::IfNotExists).to_string(),
+            "DEFINE TABLE IF NOT EXISTS user;"
+        );
+    }
+
+    #[test]
+    fn test_with_mode_leaves_incomplete_definitions_unchanged() {
+        let definition = DefineStatementRaw::from("DEFINE TABLE".to_string());
+        assert_eq!(
+            definition.with_mode(DefineMode::Overwrite).to_string(),
+            "DEFINE TABLE;"
+        );
+    }
+}
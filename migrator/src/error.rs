@@ -151,6 +151,9 @@ pub enum MigrationError {
     #[error("Invalid DefineStatement: {0}")]
     InvalidDefineStatement(String),
 
+    #[error("The {0} define policy requires SurrealDB 2.0. Enable the `surrealdb-v2` feature or use the plain define policy")]
+    UnsupportedDefinePolicy(String),
+
     // TODO: Decide on how to handle suggestions in scenarios where there is a mismatch.
     #[error("Invalid migration state. The number of registered migrations in the database ({db_migration_count}) does not match the number of migration files in the migration directory ({local_dir_migration_count}). \
         This could be because you have deleted some migration files from the migration directory or you have deleted some migration records from the database. \
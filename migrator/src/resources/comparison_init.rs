@@ -5,7 +5,9 @@
  */
 
 use serde::{Deserialize, Serialize};
-use surreal_query_builder::DbResources;
+use surreal_query_builder::{statements, DbResources};
+#[cfg(feature = "surrealdb-v2")]
+use surreal_query_builder::{Buildable, ToRaw};
 
 use crate::*;
 
@@ -26,6 +28,10 @@ macro_rules! define_top_level_resource {
                     fn get_right(&self) -> [<$resource_type>] {
                         self.resources.right_resources.[<$resource_name>]()
                     }
+
+                    fn define_policy(&self) -> DefinePolicy {
+                        self.resources.define_policy
+                    }
                 }
             )*
         }
@@ -42,6 +48,40 @@ define_top_level_resource!(
     users, Users
 );
 
+#[cfg(feature = "surrealdb-v2")]
+#[derive(Debug)]
+pub struct ComparisonAccesses<'a> {
+    pub resources: &'a ComparisonsInit<'a>,
+}
+
+#[cfg(feature = "surrealdb-v2")]
+impl<'a> DbResourcesMeta<Accesses> for ComparisonAccesses<'a> {
+    fn get_left(&self) -> Accesses {
+        self.resources.left_resources.accesses()
+    }
+
+    fn get_right(&self) -> Accesses {
+        self.resources.right_resources.accesses()
+    }
+
+    fn define_policy(&self) -> DefinePolicy {
+        self.resources.define_policy
+    }
+
+    // The access methods listed by the database info are those defined on the database
+    fn remove_statement(
+        &self,
+        name: &str,
+        _definition: &DefineStatementRaw,
+    ) -> MigrationResult<RemoveStatementRaw> {
+        Ok(statements::remove_access(name)
+            .on_database()
+            .to_raw()
+            .build()
+            .into())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ComparisonsInit<'a> {
     // Migrations directoy latest state tables
@@ -49,6 +89,8 @@ pub struct ComparisonsInit<'a> {
     // Codebase latest state tables
     pub right_resources: &'a RightFullDbInfo,
     pub prompter: &'a dyn Prompter,
+    // Modifier policy for the generated DEFINE statements
+    pub define_policy: DefinePolicy,
 }
 
 impl<'a> ComparisonsInit<'a> {
@@ -72,6 +114,11 @@ impl<'a> ComparisonsInit<'a> {
         ComparisonScopes { resources: self }
     }
 
+    #[cfg(feature = "surrealdb-v2")]
+    pub fn new_accesses(&self) -> ComparisonAccesses {
+        ComparisonAccesses { resources: self }
+    }
+
     pub fn new_tokens(&self) -> ComparisonTokens {
         ComparisonTokens { resources: self }
     }
@@ -91,11 +138,29 @@ pub struct DbInfo {
     pub functions: Functions,
     pub params: Params,
     pub scopes: Scopes,
+    // Not returned by databases that predate access methods
+    #[serde(default)]
+    pub accesses: Accesses,
     pub tables: Tables,
     pub tokens: Tokens,
     pub users: Users,
 }
 
+impl From<statements::DatabaseInfo> for DbInfo {
+    fn from(info: statements::DatabaseInfo) -> Self {
+        Self {
+            analyzers: info.analyzers.into(),
+            functions: info.functions.into(),
+            params: info.params.into(),
+            scopes: info.scopes.into(),
+            accesses: info.accesses.into(),
+            tables: info.tables.into(),
+            tokens: info.tokens.into(),
+            users: info.users.into(),
+        }
+    }
+}
+
 impl DbInfo {
     pub fn analyzers(&self) -> Analyzers {
         self.analyzers.clone()
@@ -113,6 +178,10 @@ impl DbInfo {
         self.scopes.clone()
     }
 
+    pub fn accesses(&self) -> Accesses {
+        self.accesses.clone()
+    }
+
     pub fn tables(&self) -> Tables {
         self.tables.clone()
     }
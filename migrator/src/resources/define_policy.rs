@@ -0,0 +1,65 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::fmt::Display;
+
+use clap::ValueEnum;
+use surreal_query_builder::statements::DefineMode;
+
+use crate::*;
+
+/// Decides which modifier, if any, the generated DEFINE statements of a migration get.
+#[derive(ValueEnum, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefinePolicy {
+    /// Plain `DEFINE` statements
+    #[default]
+    Plain,
+    /// `DEFINE ... IF NOT EXISTS` for newly created resources. Changed resources
+    /// still use plain `DEFINE` so that the change is applied.
+    IfNotExists,
+    /// `DEFINE ... OVERWRITE` for every defined resource.
+    Overwrite,
+}
+
+impl DefinePolicy {
+    /// `IF NOT EXISTS` and `OVERWRITE` are only understood by SurrealDB 2.0,
+    /// so only the plain policy is supported without the `surrealdb-v2` feature.
+    pub fn ensure_supported(&self) -> MigrationResult<()> {
+        match self {
+            Self::Plain => Ok(()),
+            _ if cfg!(feature = "surrealdb-v2") => Ok(()),
+            _ => Err(MigrationError::UnsupportedDefinePolicy(self.to_string())),
+        }
+    }
+
+    /// Applies the policy to the definition of a resource that does not exist yet.
+    pub fn on_create(&self, definition: DefineStatementRaw) -> DefineStatementRaw {
+        match self {
+            Self::Plain => definition,
+            Self::IfNotExists => definition.with_mode(DefineMode::IfNotExists),
+            Self::Overwrite => definition.with_mode(DefineMode::Overwrite),
+        }
+    }
+
+    /// Applies the policy to the new definition of an existing resource.
+    pub fn on_update(&self, definition: DefineStatementRaw) -> DefineStatementRaw {
+        match self {
+            Self::Plain | Self::IfNotExists => definition,
+            Self::Overwrite => definition.with_mode(DefineMode::Overwrite),
+        }
+    }
+}
+
+impl Display for DefinePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let policy = match self {
+            Self::Plain => "plain",
+            Self::IfNotExists => "if-not-exists",
+            Self::Overwrite => "overwrite",
+        };
+        write!(f, "{policy}")
+    }
+}
@@ -8,6 +8,7 @@ use crate::*;
 use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
+use surreal_query_builder::statements::Definitions;
 
 pub trait DbResourcesMeta<T>
 where
@@ -18,7 +19,19 @@ where
     // Right is from codebase
     fn get_right(&self) -> T;
 
+    fn define_policy(&self) -> DefinePolicy;
+
+    // Resources which the sql parser cannot read build their remove statement from their name
+    fn remove_statement(
+        &self,
+        _name: &str,
+        definition: &DefineStatementRaw,
+    ) -> MigrationResult<RemoveStatementRaw> {
+        definition.as_remove_statement()
+    }
+
     fn queries(&self) -> MigrationResult<Queries> {
+        let policy = self.define_policy();
         let mut queries = Queries::default();
         let left = self.get_left().get_names_as_set();
         let right = self.get_right().get_names_as_set();
@@ -30,16 +43,16 @@ where
 
             match DeltaTypeResource::from((def_left, def_right)) {
                 DeltaTypeResource::Create { right } => {
-                    queries.add_up(QueryType::Define(right.clone()));
-                    queries.add_down(QueryType::Remove(right.as_remove_statement()?));
+                    queries.add_up(QueryType::Define(policy.on_create(right.clone())));
+                    queries.add_down(QueryType::Remove(self.remove_statement(name, &right)?));
                 }
                 DeltaTypeResource::Remove { left } => {
-                    queries.add_up(QueryType::Remove(left.as_remove_statement()?));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_up(QueryType::Remove(self.remove_statement(name, &left)?));
+                    queries.add_down(QueryType::Define(policy.on_create(left)));
                 }
                 DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_up(QueryType::Define(policy.on_update(right)));
+                    queries.add_down(QueryType::Define(policy.on_update(left)));
                 }
                 DeltaTypeResource::NoChange => {}
             };
@@ -99,6 +112,17 @@ macro_rules! define_object_info {
             #[derive(Serialize, Deserialize, Clone, Debug, Default)]
             pub struct $ident(Info);
 
+            impl From<Definitions> for $ident {
+                fn from(definitions: Definitions) -> Self {
+                    Self(Info(
+                        definitions
+                            .into_iter()
+                            .map(|(name, definition)| (name, DefineStatementRaw::from(definition)))
+                            .collect(),
+                    ))
+                }
+            }
+
             // impl Deref for $ident {
             //     type Target = Info;
             //
@@ -137,5 +161,5 @@ macro_rules! define_object_info {
 }
 
 define_object_info!(
-    Analyzers, Functions, Params, Scopes, Tables, Tokens, Users, Fields, Events, Indexes
+    Analyzers, Functions, Params, Scopes, Accesses, Tables, Tokens, Users, Fields, Events, Indexes
 );
@@ -6,6 +6,7 @@
 
 pub mod change_detection;
 pub mod comparison_init;
+pub mod define_policy;
 pub mod meta;
 pub mod table_events;
 pub mod table_fields;
@@ -14,5 +15,6 @@ pub mod tables;
 
 pub use change_detection::*;
 pub use comparison_init::*;
+pub use define_policy::*;
 pub use meta::*;
 pub use tables::*;
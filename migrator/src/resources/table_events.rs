@@ -32,4 +32,8 @@ impl TableResourcesMeta<Events> for ComparisonEvents<'_> {
     fn get_table(&self) -> &Table {
         self.table
     }
+
+    fn define_policy(&self) -> DefinePolicy {
+        self.resources.define_policy
+    }
 }
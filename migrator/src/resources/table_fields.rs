@@ -34,6 +34,10 @@ impl<R: DbResources> TableResourcesMeta<Fields> for ComparisonFields<'_, R> {
         self.table
     }
 
+    fn define_policy(&self) -> DefinePolicy {
+        self.resources.define_policy
+    }
+
     // This does not use default implementation because it also has to handle
     // field name change/rename
     fn queries(&self) -> MigrationResult<Queries> {
@@ -140,7 +144,9 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
 
         // Field name change defs
         acc.add_comment_to_up(format!("Rename field {old_name} to {new_name}"));
-        acc.add_up(QueryType::Define(new_field_def.clone()));
+        acc.add_up(QueryType::Define(
+            self.define_policy().on_create(new_field_def.clone()),
+        ));
         let copy_old_to_new = UpdateStatementRaw::from(
             Raw::new(format!("UPDATE {table} SET {new_name} = {old_name}")).build(),
         );
@@ -152,7 +158,9 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
         acc.add_comment_to_down(format!(
             "Revert field name change. Change field {new_name} back to {old_name}"
         ));
-        acc.add_down(QueryType::Define(old_field_def.clone()));
+        acc.add_down(QueryType::Define(
+            self.define_policy().on_create(old_field_def.clone()),
+        ));
         let copy_new_to_old = UpdateStatementRaw::from(
             Raw::new(format!("UPDATE {table} SET {old_name} = {new_name}")).build(),
         );
@@ -167,7 +175,9 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
         acc: &'a mut Queries,
         right: &DefineStatementRaw,
     ) -> MigrationResult<&'a mut Queries> {
-        acc.add_up(QueryType::Define(right.clone()));
+        acc.add_up(QueryType::Define(
+            self.define_policy().on_create(right.clone()),
+        ));
         acc.add_down(QueryType::Remove(right.as_remove_statement()?));
         Ok(acc)
     }
@@ -178,7 +188,9 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
         left: &DefineStatementRaw,
     ) -> MigrationResult<&'a mut Queries> {
         acc.add_up(QueryType::Remove(left.as_remove_statement()?));
-        acc.add_down(QueryType::Define(left.clone()));
+        acc.add_down(QueryType::Define(
+            self.define_policy().on_create(left.clone()),
+        ));
         Ok(acc)
     }
 
@@ -188,8 +200,12 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
         left: &DefineStatementRaw,
         right: &DefineStatementRaw,
     ) -> &'a mut Queries {
-        acc.add_up(QueryType::Define(right.clone()));
-        acc.add_down(QueryType::Define(left.clone()));
+        acc.add_up(QueryType::Define(
+            self.define_policy().on_update(right.clone()),
+        ));
+        acc.add_down(QueryType::Define(
+            self.define_policy().on_update(left.clone()),
+        ));
         acc
     }
 
@@ -9,7 +9,7 @@ use crate::{
     table_indexes::ComparisonIndexes, *,
 };
 use serde::{Deserialize, Serialize};
-use surreal_query_builder::{DbResources, Table};
+use surreal_query_builder::{statements::TableInfo, DbResources, Table};
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct TableResourcesData {
@@ -19,6 +19,17 @@ pub struct TableResourcesData {
     fields: Fields,
 }
 
+impl From<TableInfo> for TableResourcesData {
+    fn from(info: TableInfo) -> Self {
+        Self {
+            events: info.events.into(),
+            indexes: info.indexes.into(),
+            tables: info.tables.into(),
+            fields: info.fields.into(),
+        }
+    }
+}
+
 impl TableResourcesData {
     pub fn events(&self) -> Events {
         self.events.clone()
@@ -54,7 +65,12 @@ impl<R: DbResources> DbResourcesMeta<Tables> for ComparisonTables<'_, R> {
         self.resources.right_resources.tables()
     }
 
+    fn define_policy(&self) -> DefinePolicy {
+        self.resources.define_policy
+    }
+
     fn queries(&self) -> MigrationResult<Queries> {
+        let policy = self.define_policy();
         let left = self.get_left().get_names_as_set();
         let right = self.get_right().get_names_as_set();
         let tables = left.union(&right);
@@ -104,21 +120,21 @@ impl<R: DbResources> DbResourcesMeta<Tables> for ComparisonTables<'_, R> {
                     extend_table_resources_down(&mut queries);
                 }
                 DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
+                    queries.add_up(QueryType::Define(policy.on_update(right)));
                     extend_table_resources_up(&mut queries);
                     extend_table_resources_down(&mut queries);
 
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_down(QueryType::Define(policy.on_update(left)));
                 }
                 DeltaTypeResource::Create { right } => {
                     queries.add_down(QueryType::Remove(right.as_remove_statement()?));
 
-                    queries.add_up(QueryType::Define(right));
+                    queries.add_up(QueryType::Define(policy.on_create(right)));
                     extend_table_resources_up(&mut queries);
                 }
                 DeltaTypeResource::Remove { left } => {
                     queries.add_up(QueryType::Remove(left.as_remove_statement()?));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_down(QueryType::Define(policy.on_create(left)));
                     extend_table_resources_down(&mut queries);
                 }
             };
@@ -156,7 +172,10 @@ where
 
     fn get_table(&self) -> &Table;
 
+    fn define_policy(&self) -> DefinePolicy;
+
     fn queries(&self) -> MigrationResult<Queries> {
+        let policy = self.define_policy();
         let left = self.get_left().get_names_as_set();
         let right = self.get_right().get_names_as_set();
         let table_resources_names = right.union(&left);
@@ -169,15 +188,15 @@ where
             match DeltaTypeResource::from((def_left, def_right)) {
                 DeltaTypeResource::Create { right } => {
                     queries.add_down(QueryType::Remove(right.as_remove_statement()?));
-                    queries.add_up(QueryType::Define(right));
+                    queries.add_up(QueryType::Define(policy.on_create(right)));
                 }
                 DeltaTypeResource::Remove { left } => {
                     queries.add_up(QueryType::Remove(left.as_remove_statement()?));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_down(QueryType::Define(policy.on_create(left)));
                 }
                 DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_up(QueryType::Define(policy.on_update(right)));
+                    queries.add_down(QueryType::Define(policy.on_update(left)));
                 }
                 DeltaTypeResource::NoChange => {}
             };
@@ -0,0 +1,58 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::time::Duration;
+
+use surreal_models::SpaceShip;
+use surreal_orm::{
+    statements::{define_table, show_changes_for_table, Change},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[tokio::test]
+async fn test_show_changes_typed() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    define_table(SpaceShip::table())
+        .changefeed(Duration::from_secs(60 * 60))
+        .run(db.clone())
+        .await?;
+
+    let ss_id = SpaceShip::create_id("num-1".into());
+    SpaceShip {
+        id: ss_id.clone(),
+        name: "spaceship-1".into(),
+        created: chrono::Utc::now(),
+    }
+    .create()
+    .run(db.clone())
+    .await?;
+    SpaceShip::delete_by_id(ss_id.clone())
+        .run(db.clone())
+        .await?;
+
+    let change_sets = show_changes_for_table(SpaceShip::table())
+        .since_versionstamp(0)
+        .return_changes::<SpaceShip>(db.clone())
+        .await?;
+
+    let changes = change_sets
+        .iter()
+        .flat_map(|change_set| change_set.changes.iter())
+        .filter(|change| !matches!(change, Change::DefineTable { .. }))
+        .collect::<Vec<_>>();
+
+    assert_eq!(changes.len(), 2);
+    assert!(matches!(changes[0], Change::Update(spaceship) if spaceship.name == "spaceship-1"));
+    assert!(matches!(changes[1], Change::Delete { id } if *id == ss_id.to_thing()));
+    assert!(change_sets
+        .windows(2)
+        .all(|pair| pair[0].versionstamp < pair[1].versionstamp));
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::time::Duration;
+
+use futures::StreamExt;
+use surreal_models::SpaceShip;
+use surreal_orm::{
+    statements::{info_for, kill, live, select, Notification, TableInfo},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    Surreal,
+};
+
+/// Waits for the driver, which kills the live queries of dropped streams in the background,
+/// and returns the number of live queries left on the table.
+async fn live_queries_left(db: Surreal<Db>) -> SurrealOrmResult<usize> {
+    let info = info_for().table(SpaceShip::table());
+    for _ in 0..50 {
+        let lives = info.get_info::<TableInfo>(db.clone()).await?.lives;
+        if lives.is_empty() {
+            return Ok(0);
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+    Ok(info.get_info::<TableInfo>(db.clone()).await?.lives.len())
+}
+
+#[tokio::test]
+async fn test_live_select_stream_notifications() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let mut stream = live(select(All).from(SpaceShip::table()))
+        .stream::<SpaceShip>(db.clone())
+        .await?;
+
+    let ss_id = SpaceShip::create_id("num-1".into());
+    let spaceship = SpaceShip {
+        id: ss_id.clone(),
+        name: "spaceship-1".into(),
+        created: chrono::Utc::now(),
+    };
+
+    spaceship.clone().create().run(db.clone()).await?;
+    SpaceShip {
+        name: "spaceship-renamed".into(),
+        ..spaceship.clone()
+    }
+    .save()
+    .run(db.clone())
+    .await?;
+    SpaceShip::delete_by_id(ss_id.clone()).run(db.clone()).await?;
+
+    let created = stream.next().await.expect("create notification")?;
+    assert!(matches!(created, Notification::Create { .. }));
+    assert_eq!(created.id().to_thing(), ss_id.to_thing());
+    assert_eq!(created.data().map(|s| s.name.as_str()), Some("spaceship-1"));
+
+    let updated = stream.next().await.expect("update notification")?;
+    assert!(matches!(updated, Notification::Update { .. }));
+    assert_eq!(
+        updated.data().map(|s| s.name.as_str()),
+        Some("spaceship-renamed")
+    );
+
+    let deleted = stream.next().await.expect("delete notification")?;
+    assert!(matches!(deleted, Notification::Delete { .. }));
+    assert_eq!(deleted.id().to_thing(), ss_id.to_thing());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_live_select_id_and_kill() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let stream = live(select(All).from(SpaceShip::table()))
+        .stream::<SpaceShip>(db.clone())
+        .await?;
+    let id = stream.id();
+    assert!(!id.0.is_nil());
+    stream.kill();
+    assert_eq!(live_queries_left(db.clone()).await?, 0);
+
+    // The live query was killed once by the driver, so killing it again fails.
+    let mut response = kill(id).run(db.clone()).await?;
+    assert_eq!(response.take_errors().len(), 1);
+
+    let stream = live(select(All).from(SpaceShip::table()))
+        .stream::<SpaceShip>(db.clone())
+        .await?;
+    let mut response = kill(stream.id()).run(db.clone()).await?;
+    assert!(response.take_errors().is_empty());
+    assert_eq!(live_queries_left(db.clone()).await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_live_select_is_killed_when_stream_is_dropped() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let stream = live(select(All).from(SpaceShip::table()))
+        .stream::<SpaceShip>(db.clone())
+        .await?;
+    let info = info_for()
+        .table(SpaceShip::table())
+        .get_info::<TableInfo>(db.clone())
+        .await?;
+    assert_eq!(info.lives.len(), 1);
+
+    drop(stream);
+
+    assert_eq!(live_queries_left(db.clone()).await?, 0);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use surreal_models::{weapon, Weapon};
+use surreal_orm::{
+    statements::{insert, select, throw},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[tokio::test]
+async fn test_query_batch_returns_typed_tuple() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let generated_weapons = (1..=10)
+        .map(|i| Weapon {
+            name: format!("Weapon {}", i),
+            strength: i as f64 * 10.0,
+            created: Utc::now(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    insert(generated_weapons).run(db.clone()).await?;
+
+    let weapon = &Weapon::table();
+    let weapon::Schema { ref strength, .. } = Weapon::schema();
+
+    let (strong_weapons, strongest, total) = query_batch!(
+        select(All)
+            .from(weapon)
+            .where_(strength.gte(80))
+            .order_by(strength.asc())
+            .batch_many::<Weapon>(),
+        select(All)
+            .from(weapon)
+            .order_by(strength.desc())
+            .limit(1)
+            .batch_one::<Weapon>(),
+        Weapon::count_all().batch_value::<u64>(),
+    )
+    .return_all(db.clone())
+    .await?;
+
+    assert_eq!(
+        strong_weapons
+            .iter()
+            .map(|w| w.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Weapon 8", "Weapon 9", "Weapon 10"]
+    );
+    assert_eq!(strongest.map(|w| w.name), Some("Weapon 10".to_string()));
+    assert_eq!(total, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_query_batch_reports_errors_by_statement_index() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let result = query_batch!(
+        select(All).from(Weapon::table()).batch_many::<Weapon>(),
+        throw("Oops").batch_many::<Weapon>(),
+    )
+    .return_all(db.clone())
+    .await;
+
+    match result {
+        Err(SurrealOrmError::QueryBatch(errors)) => {
+            assert_eq!(errors.len(), 1);
+            assert!(errors.get(0).is_none());
+            assert!(errors.get(1).is_some_and(|error| error.contains("Oops")));
+        }
+        _ => panic!("Expected the second statement of the batch to fail"),
+    }
+
+    Ok(())
+}
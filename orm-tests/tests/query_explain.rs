@@ -0,0 +1,60 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use surreal_models::{weapon, Weapon};
+use surreal_orm::{
+    statements::{assert_uses_index, define_index, insert, select},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[tokio::test]
+async fn test_explain_plan_uses_defined_index() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapon::Schema {
+        ref name,
+        ref strength,
+        ..
+    } = Weapon::schema();
+    define_index("weapon_name_idx")
+        .on_table(Weapon::table())
+        .fields(arr![name])
+        .run(db.clone())
+        .await?;
+
+    let generated_weapons = (1..=10)
+        .map(|i| Weapon {
+            name: format!("Weapon {}", i),
+            strength: i as f64 * 10.0,
+            created: Utc::now(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    insert(generated_weapons).run(db.clone()).await?;
+
+    let plan = select(All)
+        .from(Weapon::table())
+        .where_(name.eq("Weapon 3"))
+        .explain_plan(db.clone())
+        .await?;
+    assert_uses_index(&plan, "weapon_name_idx");
+    assert!(!plan.has_full_table_scan());
+    assert_eq!(plan.fetch_count(), Some(1));
+
+    let plan = select(All)
+        .from(Weapon::table())
+        .where_(strength.gt(50))
+        .explain_plan(db.clone())
+        .await?;
+    assert!(plan.indexes_used().is_empty());
+    assert_eq!(plan.full_table_scans(), vec!["weapon"]);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use surreal_models::{weapon, Weapon};
+use surreal_orm::{
+    statements::{create, select},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[tokio::test]
+async fn test_query_observer_records_runs() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let events = Arc::new(Mutex::new(vec![]));
+    let recorded = events.clone();
+    register_query_observer(move |event: &QueryEvent| {
+        recorded.lock().unwrap().push(event.clone());
+    });
+    set_slow_query_threshold(Some(Duration::ZERO));
+
+    let weapon::Schema { ref strength, .. } = Weapon::schema();
+    let statement = select(All).from(Weapon::table()).where_(strength.gt(100));
+    let weapon = Weapon {
+        name: "Laser".to_string(),
+        created: Utc::now(),
+        ..Default::default()
+    };
+    let selected = statement.return_many::<Weapon>(db.clone()).await;
+    let failed = Raw::new("THROW 'Oops'")
+        .run_with_retry(db.clone(), RetryPolicy::new(2))
+        .await;
+    let created = create()
+        .content(weapon.clone())
+        .return_one(db.clone())
+        .await;
+    let duplicate = create().content(weapon).return_one(db.clone()).await;
+
+    // Reset the global observers before any assertion can fail.
+    clear_query_observers();
+    set_slow_query_threshold(None);
+
+    selected?;
+    created?;
+    assert!(failed.is_err());
+    assert!(duplicate.is_err());
+    let events = events.lock().unwrap();
+    assert_eq!(events.len(), 4);
+
+    assert_eq!(events[0].query, statement.build());
+    assert_eq!(events[0].bindings.len(), 1);
+    assert_eq!(events[0].result_count, Some(1));
+    assert_eq!(events[0].error, None);
+    assert!(events[0].slow);
+
+    assert_eq!(events[1].query, "THROW 'Oops'");
+    assert_eq!(events[1].result_count, None);
+    assert!(events[1]
+        .error
+        .as_ref()
+        .is_some_and(|error| error.contains("Oops")));
+
+    assert_eq!(events[2].error, None);
+    assert!(events[3]
+        .error
+        .as_ref()
+        .is_some_and(|error| error.contains("already exists")));
+
+    Ok(())
+}
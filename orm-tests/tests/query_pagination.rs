@@ -0,0 +1,120 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use surreal_models::{weapon, Weapon};
+use surreal_orm::{
+    statements::{insert, select},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+async fn insert_weapons(db: &Surreal<surrealdb::engine::local::Db>, strength: impl Fn(u64) -> f64) {
+    let generated_weapons = (1..=10)
+        .map(|i| Weapon {
+            name: format!("Weapon {}", i),
+            strength: strength(i),
+            created: Utc::now(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    insert(generated_weapons).run(db.clone()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_keyset_pages_walk_ties_without_duplicates_or_gaps() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    // Only 3 distinct strengths, so most records tie on the ordered field
+    insert_weapons(&db, |i| (i % 3) as f64 * 10.0).await;
+
+    let weapon::Schema { ref strength, .. } = Weapon::schema();
+    let mut cursor = None;
+    let mut pages = vec![];
+    loop {
+        let page = select(All)
+            .from(Weapon::table())
+            .order_by(strength.desc())
+            .paginate_after(cursor, 3)
+            .return_page::<Weapon>(db.clone())
+            .await?;
+        cursor = page.next_cursor.clone();
+        pages.push(page);
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    assert_eq!(
+        pages
+            .iter()
+            .map(|page| page.items.len())
+            .collect::<Vec<_>>(),
+        vec![3, 3, 3, 1]
+    );
+    assert!(pages[..3].iter().all(|page| page.has_more));
+    assert!(!pages[3].has_more);
+
+    let weapons = pages
+        .into_iter()
+        .flat_map(|page| page.items)
+        .collect::<Vec<_>>();
+    let ids = weapons
+        .iter()
+        .map(|weapon| weapon.id.to_string())
+        .collect::<HashSet<_>>();
+    assert_eq!(ids.len(), 10);
+    assert!(weapons
+        .windows(2)
+        .all(|pair| pair[0].strength >= pair[1].strength));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_offset_page_returns_items_and_totals() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    insert_weapons(&db, |i| i as f64 * 10.0).await;
+
+    let weapon::Schema { ref strength, .. } = Weapon::schema();
+    let Paginated {
+        items,
+        total,
+        page,
+        pages,
+    } = Weapon::find_where(strength.gt(20))
+        .order_by(strength.asc())
+        .page(2, 3)
+        .return_page::<Weapon>(db.clone())
+        .await?;
+
+    assert_eq!(
+        items.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(),
+        vec!["Weapon 6", "Weapon 7", "Weapon 8"]
+    );
+    assert_eq!(total, 8);
+    assert_eq!(page, 2);
+    assert_eq!(pages, 3);
+
+    let last = Weapon::find_where(strength.gt(20))
+        .order_by(strength.asc())
+        .page(3, 3)
+        .return_page::<Weapon>(db.clone())
+        .await?;
+    assert_eq!(
+        last.items
+            .iter()
+            .map(|w| w.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Weapon 9", "Weapon 10"]
+    );
+
+    Ok(())
+}
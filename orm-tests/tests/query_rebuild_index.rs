@@ -0,0 +1,55 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// REBUILD INDEX is only supported from SurrealDB 2.0
+#![cfg(feature = "surrealdb-v2")]
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_models::migrations::Animal;
+use surreal_orm::{statements::define_event, *};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = measurement)]
+struct Measurement {
+    id: SurrealSimpleId<Self>,
+    value: f64,
+}
+
+impl TableResources for Measurement {
+    // Not an index definition
+    fn indexes_definitions() -> Vec<Raw> {
+        vec![define_event("value_changed")
+            .on_table(Self::table())
+            .when(cond(Field::new("value").gt(0)))
+            .then(Raw::new("RETURN true"))
+            .to_raw()]
+    }
+}
+
+// The pinned 1.5 engine cannot parse REBUILD INDEX, so only the built queries are tested
+// until the tests run against a 2.0 engine.
+#[test]
+fn test_rebuild_indexes() -> SurrealOrmResult<()> {
+    let rebuild = Animal::rebuild_indexes()?;
+
+    assert_eq!(
+        rebuild.build(),
+        "REBUILD INDEX species_speed_idx ON TABLE animal;"
+    );
+    assert!(rebuild.get_errors().is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_rebuild_indexes_rejects_non_index_definitions() {
+    let error = Measurement::rebuild_indexes().unwrap_err();
+
+    assert!(matches!(error, SurrealOrmError::QueryBuilder(_)));
+    assert!(error.to_string().contains("DEFINE EVENT value_changed"));
+}
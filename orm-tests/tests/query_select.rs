@@ -5,6 +5,7 @@
  */
 
 use chrono::Utc;
+use futures::TryStreamExt;
 use pretty_assertions::assert_eq;
 use surreal_models::{weapon, SpaceShip, Weapon};
 use surreal_orm::{
@@ -122,3 +123,82 @@ async fn test_subquery_in_select_statement_with_order_functions() -> SurrealOrmR
     assert!(statement[0].id.to_string().starts_with("weapon:"));
     Ok(())
 }
+
+#[tokio::test]
+async fn test_select_return_stream_fetches_all_records_in_chunks() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let generated_weapons = (1..=10)
+        .map(|i| Weapon {
+            name: format!("Weapon {}", i),
+            strength: i as f64 * 10.0,
+            created: Utc::now(),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+    insert(generated_weapons).run(db.clone()).await?;
+
+    let weapon = &Weapon::table();
+    let weapon::Schema { ref strength, .. } = Weapon::schema();
+
+    let weapons = select(All)
+        .from(weapon)
+        .where_(strength.gt(20))
+        .order_by(strength.desc())
+        .return_stream::<Weapon>(db.clone(), 3)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    assert_eq!(
+        weapons.iter().map(|w| w.name.as_str()).collect::<Vec<_>>(),
+        (3..=10)
+            .rev()
+            .map(|i| format!("Weapon {}", i))
+            .collect::<Vec<_>>()
+    );
+
+    let weapons = select(All)
+        .from(weapon)
+        .where_(strength.gt(1000))
+        .return_stream::<Weapon>(db.clone(), 3)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(weapons.is_empty());
+
+    let mut weapons = select(All)
+        .from(weapon)
+        .return_stream::<Weapon>(db.clone(), 0);
+    assert!(matches!(
+        weapons.try_next().await,
+        Err(SurrealOrmError::QueryBuilder(error)) if error.contains("Page size must be greater than 0")
+    ));
+    assert!(weapons.try_next().await?.is_none());
+
+    for statement in [
+        select(All).from(weapon).limit(5),
+        select(All).from(weapon).start(2),
+    ] {
+        let mut weapons = statement.return_stream::<Weapon>(db.clone(), 3);
+        assert!(matches!(
+            weapons.try_next().await,
+            Err(SurrealOrmError::QueryBuilder(error)) if error.contains("Cannot stream a query with a limit or start")
+        ));
+        assert!(weapons.try_next().await?.is_none());
+    }
+
+    // The ordered field is not projected, so the next chunk cannot be found
+    let weapon::Schema {
+        ref id, ref name, ..
+    } = Weapon::schema();
+    let mut weapons = select(arr![id, name])
+        .from(weapon)
+        .order_by(strength.desc())
+        .return_stream::<serde_json::Value>(db.clone(), 3);
+    assert!(matches!(
+        weapons.try_next().await,
+        Err(SurrealOrmError::QueryBuilder(error)) if error.contains("`strength` is not part of the projections")
+    ));
+
+    Ok(())
+}
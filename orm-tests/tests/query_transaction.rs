@@ -7,7 +7,7 @@
 use pretty_assertions::assert_eq;
 use surreal_models::{Account, Balance};
 use surreal_orm::{
-    statements::{begin_transaction, create_only, select, update},
+    statements::{begin_transaction, create_only, db_transaction, select, throw, update},
     *,
 };
 use surrealdb::{engine::local::Mem, Surreal};
@@ -220,3 +220,97 @@ async fn test_transaction_cancellation_increment_and_decrement_update() -> Surre
     assert_eq!(accounts.len(), 0);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_db_transaction_returns_typed_outputs() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let id1 = &Account::create_id("one".to_string());
+    let id2 = &Account::create_id("two".to_string());
+    let acc = Account::schema();
+
+    let (created, (from, to)) = db_transaction(db.clone(), |tx| {
+        let created = vec![
+            tx.add(
+                create_only()
+                    .content(Account {
+                        id: id1.clone(),
+                        balance: 135_605.16,
+                    })
+                    .batch_one::<Account>(),
+            ),
+            tx.add(
+                create_only()
+                    .content(Account {
+                        id: id2.clone(),
+                        balance: 91_031.31,
+                    })
+                    .batch_one::<Account>(),
+            ),
+        ];
+        let from = tx.add(
+            update::<Account>(id1)
+                .set(acc.balance.increment_by(300.00))
+                .batch_one::<Account>(),
+        );
+        let to = tx.add(
+            update::<Account>(id2)
+                .set(acc.balance.decrement_by(300.00))
+                .batch_one::<Account>(),
+        );
+        (created, (from, to))
+    })
+    .await?;
+
+    assert_eq!(created.len(), 2);
+    assert_eq!(from.map(|a| a.balance), Some(135_905.16));
+    assert_eq!(to.map(|a| a.balance), Some(90_731.31));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_db_transaction_names_failed_statement_and_rolls_back() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let id1 = &Account::create_id("one".to_string());
+
+    let result = db_transaction(db.clone(), |tx| {
+        let created = tx.add(
+            create_only()
+                .content(Account {
+                    id: id1.clone(),
+                    balance: 135_605.16,
+                })
+                .batch_one::<Account>(),
+        );
+        tx.query(throw("Insufficient funds"));
+        created
+    })
+    .await;
+
+    match result {
+        Err(SurrealOrmError::TransactionFailed {
+            index,
+            statement,
+            error,
+        }) => {
+            assert_eq!(index, 1);
+            assert!(statement.starts_with("THROW "));
+            assert!(
+                matches!(*error, SurrealOrmError::Thrown(ref message) if message == "Insufficient funds")
+            );
+        }
+        _ => panic!("Expected the transaction to fail at the THROW statement"),
+    }
+
+    let accounts = select(All)
+        .from(Account::table())
+        .return_many::<Account>(db.clone())
+        .await?;
+    assert!(accounts.is_empty());
+
+    Ok(())
+}
@@ -6,8 +6,9 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display, sync::LazyLock};
 
+use regex::Regex;
 use thiserror::Error;
 
 /// The length of length of the returned list of items from the database
@@ -26,6 +27,49 @@ impl From<u8> for ExpectedLength {
     }
 }
 
+/// The errors of the statements of a query batch by statement index
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchErrors(BTreeMap<usize, String>);
+
+impl BatchErrors {
+    /// Returns the error of the statement at the given index, if it failed
+    pub fn get(&self, index: usize) -> Option<&String> {
+        self.0.get(&index)
+    }
+
+    /// Returns the errors by statement index
+    pub fn iter(&self) -> impl Iterator<Item = (&usize, &String)> {
+        self.0.iter()
+    }
+
+    /// Returns the number of statements that failed
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if no statement failed
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<(usize, String)> for BatchErrors {
+    fn from_iter<I: IntoIterator<Item = (usize, String)>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Display for BatchErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let errors = self
+            .0
+            .iter()
+            .map(|(index, error)| format!("Statement {index}: {error}"))
+            .collect::<Vec<_>>();
+        write!(f, "{}", errors.join("; "))
+    }
+}
+
 type IdString = String;
 type TableString = String;
 
@@ -59,6 +103,218 @@ pub enum SurrealOrmError {
 
     #[error("Invalid subquery. {0}")]
     InvalidSubquery(String),
+
+    #[error("Problem handling live query. {0}")]
+    LiveQuery(String),
+
+    #[error("Problem running the query batch. {0}")]
+    QueryBatch(BatchErrors),
+
+    #[error("Transaction failed at statement {index}: {error}. Statement: {statement}")]
+    TransactionFailed {
+        index: usize,
+        statement: String,
+        error: Box<SurrealOrmError>,
+    },
+
+    #[error("Unique index `{index}` on table `{table}` already contains {value}")]
+    UniqueViolation {
+        index: String,
+        table: String,
+        value: String,
+    },
+
+    #[error("Found {value} for field `{field}`, but it must conform to: {assertion}")]
+    AssertionFailed {
+        field: String,
+        value: String,
+        assertion: String,
+    },
+
+    #[error("Permission denied: {0}")]
+    PermissionDenied(String),
+
+    #[error("Transaction conflict: {0}")]
+    TransactionConflict(String),
+
+    #[error("An error was thrown: {0}")]
+    Thrown(String),
+}
+
+static UNIQUE_VIOLATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"Database index `(?P<index>[^`]+)` already contains (?P<value>.+), with record `(?P<record>[^`]+)`",
+    )
+    .unwrap()
+});
+
+static ASSERTION_FAILED: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"Found (?P<value>.+) for field `(?P<field>[^`]+)`, with record `[^`]+`, but field must conform to: (?P<assertion>.+)",
+    )
+    .unwrap()
+});
+
+static THROWN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"An error occurred: (?P<message>.*)").unwrap());
+
+/// Lowercased fragments of the conflict errors of the 2.x engine ("can be retried")
+/// and of the datastore transaction errors of the 1.x engine, e.g
+/// "There was a problem with a datastore transaction: Resource busy: ".
+const TRANSACTION_CONFLICTS: [&str; 4] = [
+    "can be retried",
+    "read or write conflict",
+    "resource busy",
+    "operation timed out",
+];
+
+impl SurrealOrmError {
+    /// Classifies an error returned by the database while running a query,
+    /// e.g a unique index violation. Falls back to `QueryRun`.
+    pub fn query_run(error: surrealdb::Error) -> Self {
+        Self::classify(&error.to_string()).unwrap_or(Self::QueryRun(error))
+    }
+
+    /// Checks if the error is transient i.e running the same query again may succeed,
+    /// e.g a transaction conflict between concurrent writers.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::TransactionConflict(_) => true,
+            Self::TransactionFailed { error, .. } => error.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// Parses the message of a database error into a structured error.
+    pub(crate) fn classify(message: &str) -> Option<Self> {
+        if let Some(caps) = UNIQUE_VIOLATION.captures(message) {
+            let table = caps["record"]
+                .split_once(':')
+                .map_or(&caps["record"], |(table, _)| table);
+            return Some(Self::UniqueViolation {
+                index: caps["index"].to_string(),
+                table: table.to_string(),
+                value: caps["value"].to_string(),
+            });
+        }
+
+        if let Some(caps) = ASSERTION_FAILED.captures(message) {
+            return Some(Self::AssertionFailed {
+                field: caps["field"].to_string(),
+                value: caps["value"].to_string(),
+                assertion: caps["assertion"].to_string(),
+            });
+        }
+
+        if let Some(caps) = THROWN.captures(message) {
+            return Some(Self::Thrown(caps["message"].to_string()));
+        }
+
+        if message.contains("You don't have permission to") {
+            return Some(Self::PermissionDenied(message.to_string()));
+        }
+
+        let lowercased = message.to_lowercase();
+        if TRANSACTION_CONFLICTS
+            .iter()
+            .any(|conflict| lowercased.contains(conflict))
+        {
+            return Some(Self::TransactionConflict(message.to_string()));
+        }
+
+        None
+    }
 }
 
 pub type SurrealOrmResult<T> = std::result::Result<T, SurrealOrmError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_unique_violation() {
+        let error = SurrealOrmError::classify(
+            "Database index `email_idx` already contains 'oyelowo@example.com', with record `user:oyelowo`",
+        );
+
+        assert!(matches!(
+            error,
+            Some(SurrealOrmError::UniqueViolation { index, table, value })
+                if index == "email_idx" && table == "user" && value == "'oyelowo@example.com'"
+        ));
+    }
+
+    #[test]
+    fn test_classify_assertion_failed() {
+        let error = SurrealOrmError::classify(
+            "Found 17 for field `age`, with record `user:oyelowo`, but field must conform to: $value >= 18",
+        );
+
+        assert!(matches!(
+            error,
+            Some(SurrealOrmError::AssertionFailed { field, value, assertion })
+                if field == "age" && value == "17" && assertion == "$value >= 18"
+        ));
+    }
+
+    #[test]
+    fn test_classify_thrown_permission_and_conflict() {
+        assert!(matches!(
+            SurrealOrmError::classify("An error occurred: Insufficient funds"),
+            Some(SurrealOrmError::Thrown(message)) if message == "Insufficient funds"
+        ));
+        assert!(matches!(
+            SurrealOrmError::classify("You don't have permission to perform this query type"),
+            Some(SurrealOrmError::PermissionDenied(_))
+        ));
+        assert!(matches!(
+            SurrealOrmError::classify(
+                "Failed to commit transaction due to a read or write conflict. This transaction can be retried"
+            ),
+            Some(SurrealOrmError::TransactionConflict(_))
+        ));
+        assert!(SurrealOrmError::classify("Parse error: Failed to parse query").is_none());
+        assert!(SurrealOrmError::classify(
+            "Failed to convert `{ permission: 'admin' }` to `T`: missing field `name`"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_classify_datastore_transaction_conflict() {
+        // The 1.x engine reports a RocksDB conflict as a datastore transaction error and,
+        // when the commit fails, every statement of the transaction as not executed.
+        for message in [
+            "There was a problem with a datastore transaction: Resource busy: ",
+            "There was a problem with a datastore transaction: Operation timed out",
+            "The query was not executed due to a failed transaction. There was a problem with a datastore transaction: Resource busy: ",
+        ] {
+            assert!(
+                matches!(
+                    SurrealOrmError::classify(message),
+                    Some(SurrealOrmError::TransactionConflict(m)) if m == message
+                ),
+                "{message}"
+            );
+        }
+        assert!(SurrealOrmError::classify(
+            "There was a problem with a datastore transaction: Key already exists"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_is_transient() {
+        let conflict =
+            SurrealOrmError::TransactionConflict("This transaction can be retried".into());
+        assert!(conflict.is_transient());
+        assert!(SurrealOrmError::TransactionFailed {
+            index: 0,
+            statement: "CREATE user".into(),
+            error: Box::new(conflict),
+        }
+        .is_transient());
+        assert!(!SurrealOrmError::Thrown("Oops".into()).is_transient());
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Bytes functions
+// These functions can be used when working with bytes.
+//
+// Function	Description
+// bytes::len()	Gives the length in bytes
+
+use crate::{Buildable, Erroneous, Function, Parametric, ValueLike};
+
+/// The bytes::len function returns the length in bytes of a bytes value.
+pub fn len_fn(bytes: impl Into<ValueLike>) -> Function {
+    let bytes: ValueLike = bytes.into();
+
+    Function {
+        query_string: format!("bytes::len({})", bytes.build()),
+        bindings: bytes.get_bindings(),
+        errors: bytes.get_errors(),
+    }
+}
+
+/// The bytes::len function returns the length in bytes of a bytes value.
+/// Also aliased as `bytes_len!`.
+///
+/// # Arguments
+/// * `bytes` - The bytes to get the length of. Can also be a field or a param.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as  surreal_orm;
+/// use surreal_orm::{*, functions::{bytes, encoding}};
+///
+/// let avatar = Field::new("avatar");
+/// let result = bytes::len!(avatar);
+/// assert_eq!(result.fine_tune_params(), "bytes::len(avatar)");
+/// assert_eq!(result.to_raw().build(), "bytes::len(avatar)");
+///
+/// let result = bytes::len!(encoding::base64::decode!("aGVsbG8"));
+/// assert_eq!(result.to_raw().build(), "bytes::len(encoding::base64::decode('aGVsbG8'))");
+/// ```
+#[macro_export]
+macro_rules! bytes_len {
+    ( $bytes:expr ) => {
+        $crate::functions::bytes::len_fn($bytes)
+    };
+}
+pub use bytes_len as len;
+
+#[cfg(test)]
+mod tests {
+    use crate::{functions::bytes, *};
+
+    #[test]
+    fn test_len_fn_with_field() {
+        let avatar = Field::new("avatar");
+        let result = bytes::len_fn(avatar);
+        assert_eq!(result.fine_tune_params(), "bytes::len(avatar)");
+        assert_eq!(result.to_raw().build(), "bytes::len(avatar)");
+    }
+
+    #[test]
+    fn test_len_macro_with_param() {
+        let avatar = Param::new("avatar");
+        let result = bytes::len!(avatar);
+        assert_eq!(result.fine_tune_params(), "bytes::len($avatar)");
+        assert_eq!(result.to_raw().build(), "bytes::len($avatar)");
+    }
+}
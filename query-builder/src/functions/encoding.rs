@@ -0,0 +1,146 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Encoding functions
+// These functions can be used to encode and decode data.
+//
+// Function	Description
+// encoding::base64::decode()	Decodes a base64 encoded string into bytes
+// encoding::base64::encode()	Encodes bytes into a base64 string
+
+/// This module contains functions for working with base64 encoding.
+pub mod base64 {
+    use crate::{Buildable, Erroneous, Function, Parametric, StrandLike, ValueLike};
+
+    /// The encoding::base64::encode function encodes bytes into a base64 string.
+    pub fn encode_fn(value: impl Into<ValueLike>) -> Function {
+        let value: ValueLike = value.into();
+
+        Function {
+            query_string: format!("encoding::base64::encode({})", value.build()),
+            bindings: value.get_bindings(),
+            errors: value.get_errors(),
+        }
+    }
+
+    /// The encoding::base64::encode function encodes bytes into a base64 string.
+    /// Also aliased as `encoding_base64_encode!`.
+    ///
+    /// # Arguments
+    /// * `value` - The bytes to be encoded. Can also be a field or a param.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as  surreal_orm;
+    /// use surreal_orm::{*, statements::let_, functions::encoding};
+    ///
+    /// let avatar = Field::new("avatar");
+    /// let result = encoding::base64::encode!(avatar);
+    /// assert_eq!(result.fine_tune_params(), "encoding::base64::encode(avatar)");
+    /// assert_eq!(result.to_raw().build(), "encoding::base64::encode(avatar)");
+    ///
+    /// let avatar = let_("avatar").equal_to(Field::new("avatar"));
+    /// let result = encoding::base64::encode!(avatar.get_param());
+    /// assert_eq!(result.to_raw().build(), "encoding::base64::encode($avatar)");
+    /// ```
+    #[macro_export]
+    macro_rules! encoding_base64_encode {
+        ( $value:expr ) => {
+            $crate::functions::encoding::base64::encode_fn($value)
+        };
+    }
+    pub use encoding_base64_encode as encode;
+
+    /// The encoding::base64::decode function decodes a base64 encoded string into bytes.
+    pub fn decode_fn(value: impl Into<StrandLike>) -> Function {
+        let value: StrandLike = value.into();
+
+        Function {
+            query_string: format!("encoding::base64::decode({})", value.build()),
+            bindings: value.get_bindings(),
+            errors: value.get_errors(),
+        }
+    }
+
+    /// The encoding::base64::decode function decodes a base64 encoded string into bytes.
+    /// Also aliased as `encoding_base64_decode!`.
+    ///
+    /// # Arguments
+    /// * `value` - The base64 string to be decoded. Can also be a field or a param.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as  surreal_orm;
+    /// use surreal_orm::{*, functions::encoding};
+    ///
+    /// let result = encoding::base64::decode!("aGVsbG8");
+    /// assert_eq!(result.fine_tune_params(), "encoding::base64::decode($_param_00000001)");
+    /// assert_eq!(result.to_raw().build(), "encoding::base64::decode('aGVsbG8')");
+    ///
+    /// let encoded = Field::new("encoded");
+    /// let result = encoding::base64::decode!(encoded);
+    /// assert_eq!(result.to_raw().build(), "encoding::base64::decode(encoded)");
+    /// ```
+    #[macro_export]
+    macro_rules! encoding_base64_decode {
+        ( $value:expr ) => {
+            $crate::functions::encoding::base64::decode_fn($value)
+        };
+    }
+    pub use encoding_base64_decode as decode;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+
+    #[test]
+    fn test_base64_encode_fn_with_field() {
+        let avatar = Field::new("avatar");
+        let result = base64::encode_fn(avatar);
+        assert_eq!(
+            result.fine_tune_params(),
+            "encoding::base64::encode(avatar)"
+        );
+        assert_eq!(result.to_raw().build(), "encoding::base64::encode(avatar)");
+    }
+
+    #[test]
+    fn test_base64_encode_macro_with_param() {
+        let avatar = Param::new("avatar");
+        let result = base64::encode!(avatar);
+        assert_eq!(
+            result.fine_tune_params(),
+            "encoding::base64::encode($avatar)"
+        );
+        assert_eq!(result.to_raw().build(), "encoding::base64::encode($avatar)");
+    }
+
+    #[test]
+    fn test_base64_decode_fn_with_str() {
+        let result = base64::decode_fn("aGVsbG8");
+        assert_eq!(
+            result.fine_tune_params(),
+            "encoding::base64::decode($_param_00000001)"
+        );
+        assert_eq!(
+            result.to_raw().build(),
+            "encoding::base64::decode('aGVsbG8')"
+        );
+    }
+
+    #[test]
+    fn test_base64_decode_macro_with_field() {
+        let encoded = Field::new("encoded");
+        let result = base64::decode!(encoded);
+        assert_eq!(
+            result.fine_tune_params(),
+            "encoding::base64::decode(encoded)"
+        );
+        assert_eq!(result.to_raw().build(), "encoding::base64::decode(encoded)");
+    }
+}
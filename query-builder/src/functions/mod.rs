@@ -16,6 +16,9 @@
 /// This module contains the different types of functions for working with arrays.
 pub mod array;
 
+/// This module contains the different types of functions for working with bytes.
+pub mod bytes;
+
 // includes the count function
 mod count;
 pub use count::*;
@@ -24,6 +27,9 @@ pub use count::*;
 /// decryption.
 pub mod crypto;
 
+/// This module contains the different types of functions for encoding and decoding data.
+pub mod encoding;
+
 /// This module contains the different types of functions for working with geo data.
 pub mod geo;
 
@@ -34,6 +40,9 @@ pub mod http;
 /// operations.
 pub mod math;
 
+/// This module contains the different types of functions for working with objects.
+pub mod object;
+
 /// This module contains the different types of functions for parsing data.
 pub mod parse;
 
@@ -41,6 +50,9 @@ pub mod parse;
 pub mod rand;
 pub use self::rand::rand;
 
+/// This module contains the different types of functions for working with record ids.
+pub mod record;
+
 /// This module contains the scrypting functions.
 mod script;
 pub use script::*;
@@ -60,6 +72,12 @@ pub mod time;
 /// This module contains the different types of functions for type conversion.
 pub mod type_;
 
+/// This module contains the different types of functions for working with values of any
+/// type.
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub mod value;
+
 /// This module contains the different types of functions for deriving metadata from
 /// surrealdb record id.
 pub mod meta;
@@ -0,0 +1,292 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Object functions
+// These functions can be used when working with, and manipulating data objects.
+//
+// Function	Description
+// object::entries()	Transforms an object into an array with arrays of key-value combinations
+// object::from_entries()	Transforms an array with arrays of key-value combinations into an object
+// object::keys()	Returns an array with all the keys of an object
+// object::len()	Returns the amount of key-value pairs an object holds
+// object::values()	Returns an array with all the values of an object
+
+use crate::{ArrayLike, Buildable, Erroneous, Function, ObjectLike, Parametric};
+
+fn create_single_object_arg_helper(object: impl Into<ObjectLike>, func_name: &str) -> Function {
+    let object: ObjectLike = object.into();
+    Function {
+        query_string: format!("object::{func_name}({})", object.build()),
+        bindings: object.get_bindings(),
+        errors: object.get_errors(),
+    }
+}
+
+macro_rules! create_fn_with_single_object_arg {
+    ($(#[$attr:meta])* => $function_name:expr, $function_path:expr) => {
+        paste::paste! {
+            $(#[$attr])*
+            pub fn [<$function_name _fn>](object: impl Into<$crate::ObjectLike>) -> $crate::Function {
+                create_single_object_arg_helper(object, $function_path)
+            }
+
+            $(#[$attr])*
+            #[macro_export]
+            macro_rules! [<object_ $function_name>] {
+                ( $object:expr ) => {
+                    $crate::functions::object::[<$function_name _fn>]($object)
+                };
+            }
+            pub use [<object_ $function_name>] as [<$function_name>];
+
+            #[cfg(test)]
+            mod [<test_ $function_name>] {
+                use std::collections::BTreeMap;
+                use $crate::{functions::object, *};
+
+                #[test]
+                fn [<test_ $function_name _fn_on_field>]() {
+                    let profile = Field::new("profile");
+                    let result = object::[<$function_name _fn>](profile);
+                    assert_eq!(
+                        result.fine_tune_params(),
+                        format!("object::{}(profile)", $function_path)
+                    );
+                    assert_eq!(
+                        result.to_raw().build(),
+                        format!("object::{}(profile)", $function_path)
+                    );
+                }
+
+                #[test]
+                fn [<test_ $function_name _fn_on_object>]() {
+                    let profile = BTreeMap::from([("name".to_string(), sql::Value::from("Oyelowo"))]);
+                    let result = object::[<$function_name _fn>](profile);
+                    assert_eq!(
+                        result.fine_tune_params(),
+                        format!("object::{}($_param_00000001)", $function_path)
+                    );
+                    assert_eq!(
+                        result.to_raw().build(),
+                        format!("object::{}({{ name: 'Oyelowo' }})", $function_path)
+                    );
+                }
+
+                #[test]
+                fn [<test_ $function_name _macro_on_param>]() {
+                    let profile = Param::new("profile");
+                    let result = object::[<$function_name>]!(profile);
+                    assert_eq!(
+                        result.fine_tune_params(),
+                        format!("object::{}($profile)", $function_path)
+                    );
+                    assert_eq!(
+                        result.to_raw().build(),
+                        format!("object::{}($profile)", $function_path)
+                    );
+                }
+            }
+        }
+    };
+}
+
+create_fn_with_single_object_arg!(
+    /// The object::entries function transforms an object into an array with arrays of
+    /// key-value combinations.
+    /// Also aliased as `object_entries!`
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to transform. Could be a field or a parameter that represents the
+    /// value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use std::collections::BTreeMap;
+    /// use surreal_orm::{*, functions::object};
+    ///
+    /// let profile = Field::new("profile");
+    /// let result = object::entries!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::entries(profile)");
+    ///
+    /// let profile = BTreeMap::from([("name".to_string(), sql::Value::from("Oyelowo"))]);
+    /// let result = object::entries!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::entries({ name: 'Oyelowo' })");
+    /// ```
+    =>
+    "entries",
+    "entries"
+);
+
+create_fn_with_single_object_arg!(
+    /// The object::keys function returns an array with all the keys of an object.
+    /// Also aliased as `object_keys!`
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to get the keys of. Could be a field or a parameter that represents the
+    /// value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use std::collections::BTreeMap;
+    /// use surreal_orm::{*, functions::object};
+    ///
+    /// let profile = Field::new("profile");
+    /// let result = object::keys!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::keys(profile)");
+    ///
+    /// let profile = BTreeMap::from([("name".to_string(), sql::Value::from("Oyelowo"))]);
+    /// let result = object::keys!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::keys({ name: 'Oyelowo' })");
+    /// ```
+    =>
+    "keys",
+    "keys"
+);
+
+create_fn_with_single_object_arg!(
+    /// The object::len function returns the amount of key-value pairs an object holds.
+    /// Also aliased as `object_len!`
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to count the entries of. Could be a field or a parameter that represents the
+    /// value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use std::collections::BTreeMap;
+    /// use surreal_orm::{*, functions::object};
+    ///
+    /// let profile = Field::new("profile");
+    /// let result = object::len!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::len(profile)");
+    ///
+    /// let profile = BTreeMap::from([("name".to_string(), sql::Value::from("Oyelowo"))]);
+    /// let result = object::len!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::len({ name: 'Oyelowo' })");
+    /// ```
+    =>
+    "len",
+    "len"
+);
+
+create_fn_with_single_object_arg!(
+    /// The object::values function returns an array with all the values of an object.
+    /// Also aliased as `object_values!`
+    ///
+    /// # Arguments
+    ///
+    /// * `object` - The object to get the values of. Could be a field or a parameter that represents the
+    /// value.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use std::collections::BTreeMap;
+    /// use surreal_orm::{*, functions::object};
+    ///
+    /// let profile = Field::new("profile");
+    /// let result = object::values!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::values(profile)");
+    ///
+    /// let profile = BTreeMap::from([("name".to_string(), sql::Value::from("Oyelowo"))]);
+    /// let result = object::values!(profile);
+    /// assert_eq!(result.to_raw().build(), "object::values({ name: 'Oyelowo' })");
+    /// ```
+    =>
+    "values",
+    "values"
+);
+
+/// The object::from_entries function transforms an array with arrays of key-value
+/// combinations into an object.
+pub fn from_entries_fn(entries: impl Into<ArrayLike>) -> Function {
+    let entries: ArrayLike = entries.into();
+    Function {
+        query_string: format!("object::from_entries({})", entries.build()),
+        bindings: entries.get_bindings(),
+        errors: entries.get_errors(),
+    }
+}
+
+/// The object::from_entries function transforms an array with arrays of key-value
+/// combinations into an object.
+/// Also aliased as `object_from_entries!`
+///
+/// # Arguments
+///
+/// * `entries` - The array of key-value arrays. Could be a field or a parameter that represents the
+/// value.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, functions::object};
+///
+/// let entries = Field::new("entries");
+/// let result = object::from_entries!(entries);
+/// assert_eq!(result.to_raw().build(), "object::from_entries(entries)");
+///
+/// let entries = vec![
+///     sql::Value::from(vec!["name", "Oyelowo"]),
+///     sql::Value::from(vec!["nickname", "Oye"]),
+/// ];
+/// let result = object::from_entries!(entries);
+/// assert_eq!(
+///     result.to_raw().build(),
+///     "object::from_entries([['name', 'Oyelowo'], ['nickname', 'Oye']])"
+/// );
+/// ```
+#[macro_export]
+macro_rules! object_from_entries {
+    ( $entries:expr ) => {
+        $crate::functions::object::from_entries_fn($entries)
+    };
+}
+
+pub use object_from_entries as from_entries;
+
+#[cfg(test)]
+mod tests {
+    use crate::{functions::object, *};
+
+    #[test]
+    fn test_from_entries_fn_on_field() {
+        let entries = Field::new("entries");
+        let result = object::from_entries_fn(entries);
+        assert_eq!(result.fine_tune_params(), "object::from_entries(entries)");
+        assert_eq!(result.to_raw().build(), "object::from_entries(entries)");
+    }
+
+    #[test]
+    fn test_from_entries_macro_on_nested_arrays() {
+        let entries = vec![
+            sql::Value::from(vec!["name", "Oyelowo"]),
+            sql::Value::from(vec!["nickname", "Oye"]),
+        ];
+        let result = object::from_entries!(entries);
+        assert_eq!(
+            result.fine_tune_params(),
+            "object::from_entries($_param_00000001)"
+        );
+        assert_eq!(
+            result.to_raw().build(),
+            "object::from_entries([['name', 'Oyelowo'], ['nickname', 'Oye']])"
+        );
+    }
+
+    #[test]
+    fn test_from_entries_macro_on_param() {
+        let entries = Param::new("entries");
+        let result = object::from_entries!(entries);
+        assert_eq!(result.to_raw().build(), "object::from_entries($entries)");
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Record functions
+// These functions can be used to retrieve specific metadata from a SurrealDB Record ID.
+//
+// Function	Description
+// record::exists()	Checks if a record with the given Record ID exists
+// record::id()	Extracts and returns the identifier from a SurrealDB Record ID
+// record::tb()	Extracts and returns the table name from a SurrealDB Record ID
+//
+// record::id() and record::tb() replace meta::id() and meta::tb() from SurrealDB 2.0.
+
+use crate::{Buildable, Erroneous, Function, Parametric, ThingLike};
+
+fn create_record_fn(record_id: impl Into<ThingLike>, function_name: &str) -> Function {
+    let record_id: ThingLike = record_id.into();
+
+    Function {
+        query_string: format!("record::{function_name}({})", record_id.build()),
+        bindings: record_id.get_bindings(),
+        errors: record_id.get_errors(),
+    }
+}
+
+/// Checks if a record with the given SurrealDB Record ID exists
+pub fn exists_fn(record_id: impl Into<ThingLike>) -> Function {
+    create_record_fn(record_id, "exists")
+}
+
+/**
+Checks if a record with the given SurrealDB Record ID exists.
+Also aliased as `record_exists!`
+
+# Arguments
+* `record_id` - The record id to check. Can also be a field or a parameter
+    representing the record id.
+
+# Example
+```rust
+# use surreal_query_builder as surreal_orm;
+use surreal_orm::{*, functions::record};
+use surrealdb::sql;
+
+let record_id = sql::Thing::from(("person", "oyelowo"));
+let result = record::exists!(record_id);
+assert_eq!(result.to_raw().build(), "record::exists(person:oyelowo)");
+
+let value = Param::new("value");
+let result = record::exists!(value);
+assert_eq!(result.to_raw().build(), "record::exists($value)");
+```
+*/
+#[macro_export]
+macro_rules! record_exists {
+    ($record_id: expr) => {
+        $crate::functions::record::exists_fn($record_id)
+    };
+}
+
+pub use record_exists as exists;
+
+/// Extracts and returns the identifier from a SurrealDB Record ID
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub fn id_fn(record_id: impl Into<ThingLike>) -> Function {
+    create_record_fn(record_id, "id")
+}
+
+/**
+Extracts and returns the identifier from a SurrealDB Record ID.
+Also aliased as `record_id!`
+
+# Arguments
+* `record_id` - The record id to extract the identifier from. Can also be a field or a parameter
+    representing the record id.
+
+# Example
+```rust
+# use surreal_query_builder as surreal_orm;
+use surreal_orm::{*, functions::record};
+use surrealdb::sql;
+
+let record_id = sql::Thing::from(("person", "oyelowo"));
+let result = record::id!(record_id);
+assert_eq!(result.to_raw().build(), "record::id(person:oyelowo)");
+
+let id_field = Field::new("id_field");
+let result = record::id!(id_field);
+assert_eq!(result.to_raw().build(), "record::id(id_field)");
+```
+*/
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+#[macro_export]
+macro_rules! record_id {
+    ($record_id: expr) => {
+        $crate::functions::record::id_fn($record_id)
+    };
+}
+
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use record_id as id;
+
+/// Extracts and returns the table name from a SurrealDB Record ID
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub fn tb_fn(record_id: impl Into<ThingLike>) -> Function {
+    create_record_fn(record_id, "tb")
+}
+
+/**
+Extracts and returns the table name from a SurrealDB Record ID.
+Also aliased as `record_tb!`
+
+# Arguments
+* `record_id` - The record id to extract the table name from. Can also be a field or a parameter
+    representing the record id.
+
+# Example
+```rust
+# use surreal_query_builder as surreal_orm;
+use surreal_orm::{*, functions::record};
+use surrealdb::sql;
+
+let record_id = sql::Thing::from(("person", "oyelowo"));
+let result = record::tb!(record_id);
+assert_eq!(result.to_raw().build(), "record::tb(person:oyelowo)");
+
+let id_field = Field::new("id_field");
+let result = record::tb!(id_field);
+assert_eq!(result.to_raw().build(), "record::tb(id_field)");
+```
+*/
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+#[macro_export]
+macro_rules! record_tb {
+    ($record_id: expr) => {
+        $crate::functions::record::tb_fn($record_id)
+    };
+}
+
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use record_tb as tb;
+
+#[cfg(test)]
+mod tests {
+    use surrealdb::sql;
+
+    use super::*;
+    #[cfg(feature = "surrealdb-v2")]
+    use crate::{statements::let_, Field};
+    use crate::{Param, ToRaw};
+
+    #[test]
+    fn test_exists() {
+        let record_id = sql::Thing::from(("person", "oyelowo"));
+        let result = exists!(record_id);
+        assert_eq!(result.to_raw().build(), "record::exists(person:oyelowo)");
+
+        let value = Param::new("value");
+        let result = exists!(value);
+        assert_eq!(result.to_raw().build(), "record::exists($value)");
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_id() {
+        let record_id = sql::Thing::from(("person", "oyelowo"));
+        let result = id!(record_id);
+        assert_eq!(result.to_raw().build(), "record::id(person:oyelowo)");
+
+        let id_field = Field::new("id_field");
+        let result = id!(id_field);
+        assert_eq!(result.to_raw().build(), "record::id(id_field)");
+
+        let id_param = let_("id_param").equal_to("person:oyelowo").get_param();
+        let result = id!(id_param);
+        assert_eq!(result.to_raw().build(), "record::id($id_param)");
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_tb() {
+        let record_id = sql::Thing::from(("person", "oyelowo"));
+        let result = tb!(record_id);
+        assert_eq!(result.to_raw().build(), "record::tb(person:oyelowo)");
+
+        let id_field = Field::new("id_field");
+        let result = tb!(id_field);
+        assert_eq!(result.to_raw().build(), "record::tb(id_field)");
+
+        let id_param = let_("id_param").equal_to("person:oyelowo").get_param();
+        let result = tb!(id_param);
+        assert_eq!(result.to_raw().build(), "record::tb($id_param)");
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Value functions
+// This module contains several miscellaneous functions that can be used with values of any type.
+// They are only available from SurrealDB 2.0.
+//
+// Function	Description
+// value::diff()	Returns the JSON Patch operations that turn one value into another
+// value::patch()	Applies JSON Patch operations to a value
+
+use crate::{ArrayLike, Buildable, Erroneous, Function, Parametric, ValueLike};
+
+/// The value::diff function returns the JSON Patch operations that turn the first value
+/// into the second. The result can be deserialized as `Vec<PatchOp>`.
+pub fn diff_fn(value1: impl Into<ValueLike>, value2: impl Into<ValueLike>) -> Function {
+    let value1: ValueLike = value1.into();
+    let value2: ValueLike = value2.into();
+    let mut bindings = vec![];
+    let mut errors = vec![];
+    bindings.extend(value1.get_bindings());
+    bindings.extend(value2.get_bindings());
+    errors.extend(value1.get_errors());
+    errors.extend(value2.get_errors());
+
+    Function {
+        query_string: format!("value::diff({}, {})", value1.build(), value2.build()),
+        bindings,
+        errors,
+    }
+}
+
+/// The value::diff function returns the JSON Patch operations that turn the first value
+/// into the second. Also aliased as `value_diff!`.
+///
+/// The operations can be deserialized as `Vec<PatchOp>` and passed straight to
+/// `update().patch(...)`.
+///
+/// # Arguments
+/// * `value1` - The original value. Can also be a field or a param.
+/// * `value2` - The value to diff against. Can also be a field or a param.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as  surreal_orm;
+/// use surreal_orm::{*, statements::let_, functions::value};
+///
+/// let before = let_("before").equal_to(Field::new("profile"));
+/// let after = Param::new("after");
+/// let result = value::diff!(before.get_param(), after);
+/// assert_eq!(result.fine_tune_params(), "value::diff($before, $after)");
+/// assert_eq!(result.to_raw().build(), "value::diff($before, $after)");
+///
+/// let result = value::diff!("Oyelowo", "Oyedayo");
+/// assert_eq!(result.to_raw().build(), "value::diff('Oyelowo', 'Oyedayo')");
+/// ```
+#[macro_export]
+macro_rules! value_diff {
+    ( $value1:expr, $value2:expr ) => {
+        $crate::functions::value::diff_fn($value1, $value2)
+    };
+}
+pub use value_diff as diff;
+
+/// The value::patch function applies JSON Patch operations to a value.
+pub fn patch_fn(value: impl Into<ValueLike>, patch_ops: impl Into<ArrayLike>) -> Function {
+    let value: ValueLike = value.into();
+    let patch_ops: ArrayLike = patch_ops.into();
+    let mut bindings = vec![];
+    let mut errors = vec![];
+    bindings.extend(value.get_bindings());
+    bindings.extend(patch_ops.get_bindings());
+    errors.extend(value.get_errors());
+    errors.extend(patch_ops.get_errors());
+
+    Function {
+        query_string: format!("value::patch({}, {})", value.build(), patch_ops.build()),
+        bindings,
+        errors,
+    }
+}
+
+/// The value::patch function applies JSON Patch operations to a value.
+/// Also aliased as `value_patch!`.
+///
+/// # Arguments
+/// * `value` - The value to be patched. Can also be a field or a param.
+/// * `patch_ops` - The patch operations. Can be a list of `PatchOp`, a field or a param.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as  surreal_orm;
+/// use surreal_orm::{*, functions::value};
+///
+/// let profile = Field::new("profile");
+/// let ops = Param::new("ops");
+/// let result = value::patch!(profile, ops);
+/// assert_eq!(result.fine_tune_params(), "value::patch(profile, $ops)");
+/// assert_eq!(result.to_raw().build(), "value::patch(profile, $ops)");
+/// ```
+#[macro_export]
+macro_rules! value_patch {
+    ( $value:expr, $patch_ops:expr ) => {
+        $crate::functions::value::patch_fn($value, $patch_ops)
+    };
+}
+pub use value_patch as patch;
+
+#[cfg(test)]
+mod tests {
+    use crate::{functions::value, *};
+
+    #[test]
+    fn test_diff_fn_with_fields() {
+        let before = Field::new("before");
+        let after = Field::new("after");
+        let result = value::diff_fn(before, after);
+        assert_eq!(result.fine_tune_params(), "value::diff(before, after)");
+        assert_eq!(result.to_raw().build(), "value::diff(before, after)");
+    }
+
+    #[test]
+    fn test_diff_macro_with_raw_values() {
+        let result = value::diff!("Oyelowo", "Oyedayo");
+        assert_eq!(
+            result.fine_tune_params(),
+            "value::diff($_param_00000001, $_param_00000002)"
+        );
+        assert_eq!(result.to_raw().build(), "value::diff('Oyelowo', 'Oyedayo')");
+    }
+
+    #[test]
+    fn test_patch_fn_with_param() {
+        let profile = Field::new("profile");
+        let ops = Param::new("ops");
+        let result = value::patch_fn(profile, ops);
+        assert_eq!(result.fine_tune_params(), "value::patch(profile, $ops)");
+        assert_eq!(result.to_raw().build(), "value::patch(profile, $ops)");
+    }
+
+    #[test]
+    fn test_patch_macro_with_patch_ops() {
+        let profile = Field::new("profile");
+        let ops: Vec<PatchOp> = serde_json::from_value(serde_json::json!([
+            { "op": "replace", "path": "/name", "value": "Oyedayo" },
+            { "op": "remove", "path": "/nickname" },
+        ]))
+        .unwrap();
+        let ops = ops.into_iter().map(ValueLike::from).collect::<Vec<_>>();
+
+        let result = value::patch!(profile, ops);
+        assert_eq!(
+            result.fine_tune_params(),
+            "value::patch(profile, [{ op: 'replace', path: $_param_00000001, value: $_param_00000002 }, \
+            { op: 'remove', path: $_param_00000003 }])"
+        );
+        assert_eq!(
+            result.to_raw().build(),
+            "value::patch(profile, [{ op: 'replace', path: '/name', value: 'Oyedayo' }, \
+            { op: 'remove', path: '/nickname' }])"
+        );
+    }
+}
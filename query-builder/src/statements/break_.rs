@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use crate::traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable};
+use crate::traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement};
 
 /// Creates a BREAK statement.
 ///
@@ -24,6 +24,7 @@ pub fn break_() -> BreakStatement {
 pub struct BreakStatement;
 
 impl Queryable for BreakStatement {}
+impl SingleStatement for BreakStatement {}
 
 impl Erroneous for BreakStatement {}
 
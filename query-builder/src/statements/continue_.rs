@@ -6,7 +6,7 @@
 
 use std::fmt;
 
-use crate::traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable};
+use crate::traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement};
 
 /// Creates a CONTINUE statement builder.
 ///
@@ -25,6 +25,7 @@ pub fn continue_() -> ContinueStatement {
 pub struct ContinueStatement;
 
 impl Queryable for ContinueStatement {}
+impl SingleStatement for ContinueStatement {}
 
 impl Erroneous for ContinueStatement {}
 
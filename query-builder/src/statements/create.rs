@@ -12,7 +12,7 @@ use crate::{
     derive_binding_and_errors_from_value,
     traits::{
         BindingsList, Buildable, Erroneous, Node, Parametric, Queryable, ReturnableDefault,
-        ReturnableStandard,
+        ReturnableStandard, SingleStatement,
     },
     types::{DurationLike, ReturnType},
     ErrorList, Setter, ToRaw,
@@ -382,3 +382,4 @@ where
 }
 
 impl<T> Queryable for CreateStatement<T> where T: Serialize + DeserializeOwned + Node {}
+impl<T> SingleStatement for CreateStatement<T> where T: Serialize + DeserializeOwned + Node {}
@@ -12,7 +12,7 @@ use surrealdb::sql;
 use crate::{
     traits::{
         Binding, BindingsList, Buildable, Erroneous, Node, Parametric, Queryable,
-        ReturnableDefault, ReturnableStandard,
+        ReturnableDefault, ReturnableStandard, SingleStatement,
     },
     types::{DurationLike, ReturnType},
     ErrorList, Setter, ToRaw,
@@ -145,6 +145,7 @@ where
 }
 
 impl<T> Queryable for CreateStatementV2<T> where T: Serialize + DeserializeOwned + Node {}
+impl<T> SingleStatement for CreateStatementV2<T> where T: Serialize + DeserializeOwned + Node {}
 
 impl<T> CreateStatementV2<T>
 where
@@ -0,0 +1,415 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// DEFINE ACCESS statement
+// Access methods replace scopes and tokens. A RECORD access method lets records of a table
+// sign up and sign in, while a JWT access method verifies tokens issued by a third party.
+//
+// Requirements
+// To DEFINE ACCESS ... ON NAMESPACE ... you must have root or namespace level access.
+// To DEFINE ACCESS ... ON DATABASE ... you must have root, namespace, or database level access.
+// You must select your namespace and/or database before you can use the DEFINE ACCESS statement.
+// Statement syntax
+// DEFINE ACCESS [ OVERWRITE | IF NOT EXISTS ] @name
+//   ON [ NAMESPACE | DATABASE ]
+//   TYPE [
+//     JWT [ ALGORITHM @algorithm KEY @key | URL @url ]
+//     | RECORD
+//       [ SIGNUP @expression ]
+//       [ SIGNIN @expression ]
+//       [ WITH JWT [ ALGORITHM @algorithm KEY @key | URL @url ] ]
+//   ]
+//   [ AUTHENTICATE @expression ]
+//   [ DURATION [ FOR TOKEN @duration ] [, ] [ FOR SESSION @duration ] ]
+//
+// -- Enable record authentication directly in SurrealDB
+// DEFINE ACCESS account ON DATABASE TYPE RECORD
+// 	SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+// 	SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+// 	DURATION FOR SESSION 24h
+// ;
+use std::fmt::{self, Display};
+
+use surrealdb::sql;
+
+use crate::{
+    traits::{
+        Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement, ToRaw,
+    },
+    types::{Access, DurationLike, TokenType},
+};
+
+use super::{define_prefix, DefineMode, NamespaceOrDatabase, Subquery};
+
+/// Define a new access method.
+///
+/// Access methods allow SurrealDB to operate as a web database. A RECORD access method
+/// lets records sign up and sign in, while a JWT access method verifies the tokens
+/// issued by a third-party authentication provider.
+///
+/// Requirements
+/// To DEFINE ACCESS ... ON NAMESPACE ... you must have root or namespace level access.
+/// To DEFINE ACCESS ... ON DATABASE ... you must have root, namespace, or database level access.
+/// You must select your namespace and/or database before you can use the DEFINE ACCESS statement.
+///
+/// Examples:
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, functions::crypto, statements::{define_access, select}};
+/// use std::time::Duration;
+///
+/// # let user = Table::new("user");
+/// # let email = Field::new("email");
+/// # let pass = Field::new("pass");
+/// # let pass_param = Param::new("pass_param");
+/// let statement = define_access("account")
+///     .on_database()
+///     .type_record()
+///     .signup(Raw::new(
+///         "CREATE user SET email = $email, pass = crypto::argon2::generate($pass)",
+///     ))
+///     .signin(
+///         select(All).from(user).where_(
+///             cond(email.equal("oyelowo@codebreather.com"))
+///                 .and(crypto::argon2::compare!(pass, pass_param)),
+///         ),
+///     )
+///     .duration_for_session(Duration::from_secs(60 * 60 * 24));
+/// assert_eq!(
+///     statement.to_raw().build(),
+///     "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+///         \n\tSIGNUP (CREATE user SET email = $email, pass = crypto::argon2::generate($pass)) \
+///         \n\tSIGNIN (SELECT * FROM user WHERE (email = 'oyelowo@codebreather.com') AND (crypto::argon2::compare(pass, $pass_param))) \
+///         DURATION FOR SESSION 1d;"
+/// );
+///
+/// let statement = define_access("token")
+///     .on_database()
+///     .type_jwt()
+///     .algorithm(TokenType::HS512, "secret");
+/// assert_eq!(
+///     statement.to_raw().build(),
+///     "DEFINE ACCESS token ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'secret';"
+/// );
+///
+/// let statement = define_access("auth0")
+///     .on_namespace()
+///     .type_jwt()
+///     .url("https://example.com/.well-known/jwks.json");
+/// assert_eq!(
+///     statement.to_raw().build(),
+///     "DEFINE ACCESS auth0 ON NAMESPACE TYPE JWT URL 'https://example.com/.well-known/jwks.json';"
+/// );
+/// ```
+pub fn define_access(name: impl Into<Access>) -> DefineAccessStatement {
+    DefineAccessStatement {
+        name: name.into(),
+        mode: None,
+        target: None,
+        access_type: None,
+        signup_expression: None,
+        signin_expression: None,
+        jwt: None,
+        authenticate_expression: None,
+        token_duration: None,
+        session_duration: None,
+        bindings: vec![],
+    }
+}
+
+/// Define the API for the Access builder
+pub struct DefineAccessStatement {
+    name: Access,
+    mode: Option<DefineMode>,
+    target: Option<NamespaceOrDatabase>,
+    access_type: Option<AccessType>,
+    signup_expression: Option<String>,
+    signin_expression: Option<String>,
+    jwt: Option<JwtVerification>,
+    authenticate_expression: Option<String>,
+    token_duration: Option<String>,
+    session_duration: Option<String>,
+    bindings: BindingsList,
+}
+
+impl DefineAccessStatement {
+    /// Only define the access method if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the access method definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Define the access method for the namespace
+    pub fn on_namespace(mut self) -> Self {
+        self.target = Some(NamespaceOrDatabase::Namespace);
+        self
+    }
+
+    /// Define the access method for the current database
+    pub fn on_database(mut self) -> Self {
+        self.target = Some(NamespaceOrDatabase::Database);
+        self
+    }
+
+    /// Authenticate records of a table using the `signup` and `signin` expressions
+    pub fn type_record(mut self) -> Self {
+        self.access_type = Some(AccessType::Record);
+        self
+    }
+
+    /// Authenticate with tokens issued by a third-party provider
+    pub fn type_jwt(mut self) -> Self {
+        self.access_type = Some(AccessType::Jwt);
+        self
+    }
+
+    /// Set the signup expression of a record access method
+    pub fn signup(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.signup_expression = Some(subquery.build());
+        self
+    }
+
+    /// Set the signin expression of a record access method
+    pub fn signin(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.signin_expression = Some(subquery.build());
+        self
+    }
+
+    /// Verify tokens with the given cryptographic signature algorithm and key.
+    /// On a record access method, this is the key used to sign and verify its tokens.
+    pub fn algorithm(mut self, algorithm: TokenType, key: impl Into<sql::Strand>) -> Self {
+        let binding = Binding::new(key.into());
+        self.jwt = Some(JwtVerification::Key {
+            algorithm,
+            key: binding.get_param_dollarised(),
+        });
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Verify tokens with the JSON Web Key Set served at the given url
+    pub fn url(mut self, url: impl Into<sql::Strand>) -> Self {
+        let binding = Binding::new(url.into());
+        self.jwt = Some(JwtVerification::Url(binding.get_param_dollarised()));
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Set the expression run after a successful signin or token verification
+    pub fn authenticate(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.authenticate_expression = Some(subquery.build());
+        self
+    }
+
+    /// Set how long the issued tokens are valid for
+    pub fn duration_for_token(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.token_duration = Some(duration.to_raw().build());
+        self
+    }
+
+    /// Set how long the authenticated sessions last for
+    pub fn duration_for_session(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.session_duration = Some(duration.to_raw().build());
+        self
+    }
+}
+
+impl Buildable for DefineAccessStatement {
+    fn build(&self) -> String {
+        let mut query = format!("{} {}", define_prefix("ACCESS", self.mode), self.name);
+
+        if let Some(target) = &self.target {
+            query = format!("{query} ON {target}");
+        }
+
+        match self.access_type {
+            Some(AccessType::Record) => {
+                query = format!("{query} TYPE RECORD");
+
+                if let Some(signup) = &self.signup_expression {
+                    query = format!("{query} \n\tSIGNUP {signup}");
+                }
+
+                if let Some(signin) = &self.signin_expression {
+                    query = format!("{query} \n\tSIGNIN {signin}");
+                }
+
+                if let Some(jwt) = &self.jwt {
+                    query = format!("{query} \n\tWITH JWT {jwt}");
+                }
+            }
+            Some(AccessType::Jwt) => {
+                query = format!("{query} TYPE JWT");
+
+                if let Some(jwt) = &self.jwt {
+                    query = format!("{query} {jwt}");
+                }
+            }
+            None => {}
+        }
+
+        if let Some(authenticate) = &self.authenticate_expression {
+            query = format!("{query} \n\tAUTHENTICATE {authenticate}");
+        }
+
+        let durations = [
+            self.token_duration
+                .as_ref()
+                .map(|duration| format!("FOR TOKEN {duration}")),
+            self.session_duration
+                .as_ref()
+                .map(|duration| format!("FOR SESSION {duration}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if !durations.is_empty() {
+            query = format!("{query} DURATION {}", durations.join(", "));
+        }
+
+        query += ";";
+        query
+    }
+}
+
+impl Display for DefineAccessStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl Parametric for DefineAccessStatement {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.to_vec()
+    }
+}
+
+impl Queryable for DefineAccessStatement {}
+impl SingleStatement for DefineAccessStatement {}
+
+impl Erroneous for DefineAccessStatement {}
+
+enum AccessType {
+    Record,
+    Jwt,
+}
+
+enum JwtVerification {
+    Key { algorithm: TokenType, key: String },
+    Url(String),
+}
+
+impl Display for JwtVerification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JwtVerification::Key { algorithm, key } => write!(f, "ALGORITHM {algorithm} KEY {key}"),
+            JwtVerification::Url(url) => write!(f, "URL {url}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        functions::crypto,
+        statements::{define_access, select},
+        *,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_define_access_record_statement() {
+        let user = Table::new("user");
+        let email = Field::new("email");
+        let pass = Field::new("pass");
+        let pass_param = Param::new("pass_param");
+
+        let statement = define_access("account")
+            .on_database()
+            .type_record()
+            .signup(Raw::new(
+                "CREATE user SET email = $email, pass = crypto::argon2::generate($pass)",
+            ))
+            .signin(
+                select(All).from(user).where_(
+                    cond(email.equal("oyelowo@codebreather.com"))
+                        .and(crypto::argon2::compare!(pass, pass_param)),
+                ),
+            )
+            .algorithm(TokenType::HS512, "secret")
+            .duration_for_token(Duration::from_secs(60 * 15))
+            .duration_for_session(Duration::from_secs(60 * 60 * 12));
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+                \n\tSIGNUP $_param_00000001 \
+                \n\tSIGNIN $_param_00000002 \
+                \n\tWITH JWT ALGORITHM HS512 KEY $_param_00000003 \
+                DURATION FOR TOKEN 15m, FOR SESSION 12h;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+                \n\tSIGNUP (CREATE user SET email = $email, pass = crypto::argon2::generate($pass)) \
+                \n\tSIGNIN (SELECT * FROM user WHERE (email = 'oyelowo@codebreather.com') AND (crypto::argon2::compare(pass, $pass_param))) \
+                \n\tWITH JWT ALGORITHM HS512 KEY 'secret' \
+                DURATION FOR TOKEN 15m, FOR SESSION 12h;"
+        );
+        assert_eq!(statement.get_bindings().len(), 3);
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_access_jwt_statement() {
+        let statement = define_access("token")
+            .overwrite()
+            .on_namespace()
+            .type_jwt()
+            .algorithm(TokenType::RS256, "public-key")
+            .authenticate(Raw::new(
+                "IF $token.iss != 'surrealdb' { THROW 'Invalid issuer' }",
+            ));
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS OVERWRITE token ON NAMESPACE TYPE JWT ALGORITHM RS256 KEY 'public-key' \
+                \n\tAUTHENTICATE (IF $token.iss != 'surrealdb' { THROW 'Invalid issuer' });"
+        );
+
+        let statement = define_access("auth0")
+            .on_database()
+            .type_jwt()
+            .url("https://example.com/.well-known/jwks.json")
+            .duration_for_session(Duration::from_secs(60 * 60 * 2));
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS auth0 ON DATABASE TYPE JWT URL 'https://example.com/.well-known/jwks.json' \
+                DURATION FOR SESSION 2h;"
+        );
+    }
+}
@@ -6,7 +6,11 @@
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable};
+use crate::{
+    BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable, SingleStatement,
+};
+
+use super::{define_prefix, DefineMode};
 
 #[allow(missing_docs)]
 #[derive(Debug, Clone)]
@@ -100,6 +104,7 @@ impl Display for AnalyzerFilter {
 #[derive(Clone, Debug)]
 pub struct DefineAnalyzerStatement {
     name: String,
+    mode: Option<DefineMode>,
     tokenizers: Vec<Tokenizer>,
     filters: Vec<AnalyzerFilter>,
     bindings: BindingsList,
@@ -122,6 +127,7 @@ pub fn define_analyzer(name: &str) -> DefineAnalyzerStatement {
     DefineAnalyzerStatement {
         name: name.to_string(),
         tokenizers: vec![],
+        mode: None,
         filters: vec![],
         bindings: vec![],
         errors: vec![],
@@ -129,6 +135,22 @@ pub fn define_analyzer(name: &str) -> DefineAnalyzerStatement {
 }
 
 impl DefineAnalyzerStatement {
+    /// Only define the analyzer if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the analyzer definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Add a tokenizer to the analyzer
     pub fn tokenizers<I>(mut self, tokenizers: I) -> Self
     where
@@ -150,6 +172,7 @@ impl DefineAnalyzerStatement {
 }
 
 impl Queryable for DefineAnalyzerStatement {}
+impl SingleStatement for DefineAnalyzerStatement {}
 impl Erroneous for DefineAnalyzerStatement {
     fn get_errors(&self) -> ErrorList {
         self.errors.to_vec()
@@ -164,7 +187,7 @@ impl Parametric for DefineAnalyzerStatement {
 
 impl Buildable for DefineAnalyzerStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE ANALYZER {}", self.name);
+        let mut query = format!("{} {}", define_prefix("ANALYZER", self.mode), self.name);
 
         if !self.tokenizers.is_empty() {
             let tokenizers_str = self
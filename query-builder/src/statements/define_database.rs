@@ -7,10 +7,13 @@
 use std::fmt::Display;
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
-    types::Database,
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
+    types::{Database, DurationLike},
+    ToRaw,
 };
 
+use super::{define_prefix, DefineMode};
+
 /// Define a new database statement.
 ///
 /// # Arguments
@@ -40,6 +43,8 @@ use crate::{
 pub fn define_database(database: impl Into<Database>) -> DefineDatabaseStatement {
     DefineDatabaseStatement {
         database: database.into().into(),
+        changefeed: None,
+        mode: None,
         bindings: vec![],
     }
 }
@@ -47,12 +52,59 @@ pub fn define_database(database: impl Into<Database>) -> DefineDatabaseStatement
 /// A statement for defining a database.
 pub struct DefineDatabaseStatement {
     database: String,
+    mode: Option<DefineMode>,
+    changefeed: Option<String>,
     bindings: BindingsList,
 }
 
+impl DefineDatabaseStatement {
+    /// Only define the database if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the database definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
+    /// Enable the change feed for all tables of the database. Changes are kept
+    /// for the given duration and can be read with the SHOW CHANGES statement.
+    ///
+    /// # Example
+    /// ```rust
+    /// use surreal_query_builder::{*, statements::define_database};
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(
+    ///     define_database("codebreather")
+    ///         .changefeed(Duration::from_secs(60 * 60))
+    ///         .build(),
+    ///     "DEFINE DATABASE codebreather CHANGEFEED 1h;"
+    /// );
+    /// ```
+    pub fn changefeed(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.changefeed = Some(duration.to_raw().build());
+        self
+    }
+}
+
 impl Buildable for DefineDatabaseStatement {
     fn build(&self) -> String {
-        format!("DEFINE DATABASE {};", self.database)
+        let mut query = format!("{} {}", define_prefix("DATABASE", self.mode), self.database);
+
+        if let Some(duration) = &self.changefeed {
+            query = format!("{query} CHANGEFEED {duration}");
+        }
+
+        format!("{query};")
     }
 }
 
@@ -69,11 +121,13 @@ impl Parametric for DefineDatabaseStatement {
 }
 
 impl Queryable for DefineDatabaseStatement {}
+impl SingleStatement for DefineDatabaseStatement {}
 impl Erroneous for DefineDatabaseStatement {}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_define_statement() {
@@ -82,4 +136,12 @@ mod tests {
             "DEFINE DATABASE oyelowo;"
         );
     }
+
+    #[test]
+    fn test_define_statement_with_changefeed() {
+        let statement = define_database("oyelowo").changefeed(Duration::from_secs(60 * 30));
+
+        assert_eq!(statement.build(), "DEFINE DATABASE oyelowo CHANGEFEED 30m;");
+        assert_eq!(statement.get_bindings().len(), 0);
+    }
 }
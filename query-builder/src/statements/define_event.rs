@@ -7,9 +7,12 @@
 use std::fmt::{self, Display};
 
 use crate::{
-    BindingsList, Buildable, Conditional, Erroneous, Event, Filter, Parametric, Queryable, Table,
+    BindingsList, Buildable, Conditional, Erroneous, Event, Filter, Parametric, Queryable,
+    SingleStatement, Table,
 };
 
+use super::{define_prefix, DefineMode};
+
 // DEFINE EVENT statement
 // Events can be triggered after any change or modification to the data in a record. Each trigger is able to see the $before and $after value of the record, enabling advanced custom logic with each trigger.
 //
@@ -28,6 +31,7 @@ use crate::{
 
 pub struct EventBuilder {
     event: String,
+    mode: Option<DefineMode>,
     on_table: Option<String>,
     when: Option<String>,
     then_string: Option<String>,
@@ -87,12 +91,29 @@ pub fn define_event(event_name: impl Into<Event>) -> EventBuilder {
 }
 
 impl EventBuilder {
+    /// Only define the event if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the event definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the event name
     fn new(event_name: impl Into<Event>) -> Self {
         Self {
             event: event_name.into().to_string(),
             on_table: None,
             when: None,
+            mode: None,
             then_string: None,
             bindings: vec![],
         }
@@ -129,7 +150,7 @@ pub struct DefineEventStatement(EventBuilder);
 // DEFINE EVENT @name ON [ TABLE ] @table WHEN @expression THEN @expression
 impl Buildable for DefineEventStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE EVENT {}", &self.0.event);
+        let mut query = format!("{} {}", define_prefix("EVENT", self.0.mode), &self.0.event);
         if let Some(table) = &self.0.on_table {
             query = format!("{query} ON TABLE {table}");
         }
@@ -160,6 +181,7 @@ impl Parametric for DefineEventStatement {
 }
 
 impl Queryable for DefineEventStatement {}
+impl SingleStatement for DefineEventStatement {}
 
 impl Erroneous for DefineEventStatement {}
 
@@ -8,10 +8,10 @@ use std::fmt::{self, Display};
 
 use crate::{
     BindingsList, Buildable, Conditional, Erroneous, Field, FieldType, Filter, Parametric,
-    Queryable, Table, ValueLike,
+    Queryable, SingleStatement, Table, ValueLike,
 };
 
-use super::for_permission::Permissions;
+use super::{define_prefix, for_permission::Permissions, DefineMode};
 
 // DEFINE FIELD statement
 // The DEFINE FIELD statement allows you to instantiate a named field on a table, enabling you to set the field's data type, set a default value, apply assertions to protect data consistency, and set permissions specifying what operations can be performed on the field.
@@ -35,8 +35,12 @@ use super::for_permission::Permissions;
 #[derive(Clone, Debug)]
 pub struct DefineFieldStatement {
     field_name: String,
+    mode: Option<DefineMode>,
     table: Option<String>,
     type_: Option<String>,
+    flexible: Option<bool>,
+    default: Option<String>,
+    readonly: Option<bool>,
     value: Option<String>,
     assert: Option<String>,
     permissions_none: Option<bool>,
@@ -83,6 +87,10 @@ pub fn define_field(fieldable: impl Into<Field>) -> DefineFieldStatement {
         field_name: field.to_string(),
         table: None,
         type_: None,
+        mode: None,
+        flexible: None,
+        default: None,
+        readonly: None,
         value: None,
         assert: None,
         permissions_none: None,
@@ -93,6 +101,22 @@ pub fn define_field(fieldable: impl Into<Field>) -> DefineFieldStatement {
 }
 
 impl DefineFieldStatement {
+    /// Only define the field if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the field definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the table where the field is defined.
     pub fn on_table(mut self, table: impl Into<Table>) -> Self {
         let table: Table = table.into();
@@ -107,6 +131,47 @@ impl DefineFieldStatement {
         self
     }
 
+    /// Allow the field to store schemaless nested content when its type is an object
+    /// on a SCHEMAFULL table.
+    pub fn flexible(mut self) -> Self {
+        self.flexible = Some(true);
+        self
+    }
+
+    /// Set the value the field gets when it is created without one. Unlike `value`,
+    /// this is only used when no value is given and does not override a given value.
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::define_field};
+    ///
+    /// # let status = Field::new("status");
+    /// let statement = define_field(status)
+    ///     .on_table("user")
+    ///     .type_(FieldType::String)
+    ///     .default("pending");
+    ///
+    /// assert_eq!(
+    ///     statement.to_raw().build(),
+    ///     "DEFINE FIELD status ON TABLE user TYPE string DEFAULT 'pending';"
+    /// );
+    /// ```
+    pub fn default(mut self, default_value: impl Into<ValueLike>) -> Self {
+        let value: ValueLike = default_value.into();
+        self.default = Some(value.build());
+        self.bindings.extend(value.get_bindings());
+        self
+    }
+
+    /// Make the field readonly. The field can be set when the record is created
+    /// but cannot be changed afterwards. READONLY requires SurrealDB 2.0.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn readonly(mut self) -> Self {
+        self.readonly = Some(true);
+        self
+    }
+
     /// Set the default value for the field.
     pub fn value(mut self, default_value: impl Into<ValueLike>) -> Self {
         // pub fn value<T, U>(mut self, default_value: U) where T: Deref<Target = U>, T: Into<U>, U: Into<T>, T: Into<ValueLike>, T: Into<sql::Value> -> Self {
@@ -224,6 +289,7 @@ impl DefineFieldStatement {
 }
 
 impl Queryable for DefineFieldStatement {}
+impl SingleStatement for DefineFieldStatement {}
 impl Erroneous for DefineFieldStatement {}
 
 impl Parametric for DefineFieldStatement {
@@ -234,16 +300,28 @@ impl Parametric for DefineFieldStatement {
 
 impl Buildable for DefineFieldStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE FIELD {}", &self.field_name);
+        let mut query = format!("{} {}", define_prefix("FIELD", self.mode), &self.field_name);
 
         if let Some(table) = &self.table {
             query = format!("{query} ON TABLE {table}");
         }
 
+        if let Some(true) = self.flexible {
+            query = format!("{query} FLEXIBLE");
+        }
+
         if let Some(field_type) = &self.type_ {
             query = format!("{query} TYPE {field_type}");
         }
 
+        if let Some(default) = &self.default {
+            query = format!("{query} DEFAULT {default}");
+        }
+
+        if let Some(true) = self.readonly {
+            query = format!("{query} READONLY");
+        }
+
         if let Some(value) = &self.value {
             query = format!("{query} VALUE $value OR {value}");
         }
@@ -276,6 +354,7 @@ mod tests {
     use super::*;
     use crate::{cond, value, Operatable, ToRaw, NONE};
     use crate::{statements::for_permission, CrudType::*};
+    use surrealdb::sql;
 
     #[test]
     fn test_define_field_statement_full() {
@@ -337,4 +416,49 @@ mod tests {
         insta::assert_snapshot!(statement.fine_tune_params());
         assert_eq!(statement.get_bindings().len(), 0);
     }
+
+    #[test]
+    fn test_define_field_statement_flexible_default() {
+        use FieldType::*;
+
+        let created_at = Field::new("created_at");
+        let settings = Field::new("settings");
+        let user_table = Table::from("user");
+
+        let statement = define_field(created_at)
+            .on_table(user_table.clone())
+            .type_(Datetime)
+            .default(sql::Datetime::default());
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE FIELD created_at ON TABLE user TYPE datetime DEFAULT $_param_00000001;"
+        );
+        assert_eq!(statement.get_bindings().len(), 1);
+
+        let statement = define_field(settings)
+            .on_table(user_table)
+            .flexible()
+            .type_(Object);
+
+        assert_eq!(
+            statement.build(),
+            "DEFINE FIELD settings ON TABLE user FLEXIBLE TYPE object;"
+        );
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_field_statement_readonly() {
+        let statement = define_field(Field::new("created_at"))
+            .on_table(Table::from("user"))
+            .type_(FieldType::Datetime)
+            .default(sql::Datetime::default())
+            .readonly();
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE FIELD created_at ON TABLE user TYPE datetime DEFAULT $_param_00000001 READONLY;"
+        );
+    }
 }
@@ -6,8 +6,11 @@
 
 use crate::{
     BindingsList, Block, Buildable, Erroneous, ErrorList, FieldType, Param, Parametric, Queryable,
+    SingleStatement,
 };
 
+use super::{define_prefix, DefineMode};
+
 /// Represents a surrealdb define function statement argument
 #[derive(Debug, Clone)]
 pub struct FunctionArgument {
@@ -21,6 +24,7 @@ pub struct FunctionArgument {
 #[derive(Debug, Clone)]
 pub struct DefineFunctionStatement {
     name: String,
+    mode: Option<DefineMode>,
     args: Vec<FunctionArgument>,
     body: Option<Block>,
     bindings: BindingsList,
@@ -37,11 +41,28 @@ impl DefineFunctionStatement {
             name,
             args: vec![],
             body: None,
+            mode: None,
             bindings: vec![],
             errors: vec![],
         }
     }
 
+    /// Only define the function if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the function definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Sets the arguments for the function
     pub fn arguments(mut self, args: Vec<FunctionArgument>) -> Self {
         self.args = args;
@@ -64,6 +85,7 @@ pub fn define_function(name: impl Into<String>) -> DefineFunctionStatement {
         name: name.into(),
         args: vec![],
         body: None,
+        mode: None,
         bindings: vec![],
         errors: vec![],
     }
@@ -82,10 +104,15 @@ impl Erroneous for DefineFunctionStatement {
 }
 
 impl Queryable for DefineFunctionStatement {}
+impl SingleStatement for DefineFunctionStatement {}
 
 impl Buildable for DefineFunctionStatement {
     fn build(&self) -> String {
-        let mut build = format!("DEFINE FUNCTION fn::{}(", self.name);
+        let mut build = format!(
+            "{} fn::{}(",
+            define_prefix("FUNCTION", self.mode),
+            self.name
+        );
         build.push_str(
             &self
                 .args
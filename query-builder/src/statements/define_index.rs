@@ -13,7 +13,9 @@
 // You must select your namespace and database before you can use the DEFINE INDEX statement.
 // Statement syntax
 // DEFINE INDEX @name ON [ TABLE ] @table [ FIELDS | COLUMNS ] @fields
-// 	[ UNIQUE | SEARCH ANALYZER @analyzer [ BM25 [(@k1, @b)] ] [ HIGHLIGHTS ] ]
+// 	[ UNIQUE | SEARCH ANALYZER @analyzer [ BM25 [(@k1, @b)] ] [ HIGHLIGHTS ]
+// 	| MTREE DIMENSION @dimension [ DIST @distance ] [ TYPE @type ] [ CAPACITY @capacity ]
+// 	| HNSW DIMENSION @dimension [ DIST @distance ] [ TYPE @type ] [ EFC @efc ] [ M @m ] ]
 // Example usage
 // How to create a unique index for the email address field on a user table.
 //
@@ -27,15 +29,21 @@
 //
 // -- Allow full-text search queries on the name of the user
 // DEFINE INDEX userNameIndex ON TABLE user COLUMNS name SEARCH ANALYZER ascii BM25 HIGHLIGHTS;
+// How to create a vector index for an embedding field on a document table.
+//
+// -- Allow nearest neighbour queries on the embedding of the document
+// DEFINE INDEX docEmbeddingIndex ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST EUCLIDEAN TYPE F32;
 
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::{Field, TableIndex},
     ErrorList, NumberLike, TableLike, ValueLike,
 };
 
+use super::{define_prefix, DefineMode};
+
 /// Define a new database index.
 /// Just like in other databases, SurrealDB uses indexes to help optimize query performance.
 /// An index can consist of one or more fields in a table and can enforce a uniqueness constraint.
@@ -71,11 +79,13 @@ pub fn define_index(index_name: impl Into<TableIndex>) -> DefineIndexStatement {
 
     DefineIndexStatement {
         index_name,
+        mode: None,
         table: None,
         fields: vec![],
         columns: vec![],
         unique: None,
         search_analyzer: None,
+        vector_index: None,
         bindings: vec![],
         errors: vec![],
     }
@@ -157,16 +167,34 @@ impl Parametric for Columns {
 /// A statement for defining a database Index.
 pub struct DefineIndexStatement {
     index_name: String,
+    mode: Option<DefineMode>,
     table: Option<String>,
     fields: Vec<Field>,
     columns: Vec<Field>,
     unique: Option<bool>,
     search_analyzer: Option<SearchAnalyzer>,
+    vector_index: Option<VectorIndex>,
     bindings: BindingsList,
     errors: ErrorList,
 }
 
 impl DefineIndexStatement {
+    /// Only define the index if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the index definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the table where the index is defined.
     pub fn on_table(mut self, table: impl Into<TableLike>) -> Self {
         let table: TableLike = table.into();
@@ -228,11 +256,118 @@ impl DefineIndexStatement {
         // self.errors.extend(search_analyzer.get_errors());
         self
     }
+
+    /// Define an M-Tree vector index for the field. Used for nearest neighbour
+    /// queries with the `knn` operator.
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{define_index, VectorDistance, VectorType}};
+    /// # let embedding = Field::new("embedding");
+    ///
+    /// let query = define_index("docEmbeddingIndex")
+    ///     .on_table("document")
+    ///     .fields(embedding)
+    ///     .mtree(4, VectorDistance::Euclidean, VectorType::F32)
+    ///     .capacity(40);
+    ///
+    /// assert_eq!(query.to_raw().build(),
+    /// "DEFINE INDEX docEmbeddingIndex ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST EUCLIDEAN TYPE F32 CAPACITY 40;");
+    /// ```
+    pub fn mtree(
+        mut self,
+        dimension: u32,
+        distance: VectorDistance,
+        vector_type: VectorType,
+    ) -> Self {
+        self.vector_index = Some(VectorIndex::Mtree {
+            dimension,
+            distance,
+            vector_type,
+            capacity: None,
+        });
+        self
+    }
+
+    /// Define an HNSW (Hierarchical Navigable Small World) vector index for the field.
+    /// Used for approximate nearest neighbour queries with the `knn` and `knn_with_ef` operators.
+    /// HNSW indexes require SurrealDB 2.0, so this is only available with the `surrealdb-v2` feature.
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{define_index, VectorDistance, VectorType}};
+    /// # let embedding = Field::new("embedding");
+    ///
+    /// let query = define_index("docEmbeddingIndex")
+    ///     .on_table("document")
+    ///     .fields(embedding)
+    ///     .hnsw(4, VectorDistance::Cosine, VectorType::F64)
+    ///     .efc(150)
+    ///     .m(12);
+    ///
+    /// assert_eq!(query.to_raw().build(),
+    /// "DEFINE INDEX docEmbeddingIndex ON TABLE document FIELDS embedding HNSW DIMENSION 4 DIST COSINE TYPE F64 EFC 150 M 12;");
+    /// ```
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn hnsw(
+        mut self,
+        dimension: u32,
+        distance: VectorDistance,
+        vector_type: VectorType,
+    ) -> Self {
+        self.vector_index = Some(VectorIndex::Hnsw {
+            dimension,
+            distance,
+            vector_type,
+            efc: None,
+            m: None,
+        });
+        self
+    }
+
+    /// Set the capacity of the nodes of an M-Tree index. Must be called after `mtree`.
+    pub fn capacity(mut self, capacity: u32) -> Self {
+        match &mut self.vector_index {
+            Some(VectorIndex::Mtree { capacity: c, .. }) => *c = Some(capacity),
+            _ => self.errors.push(
+                "CAPACITY only applies to MTREE indexes. Call `mtree` before `capacity`"
+                    .to_string(),
+            ),
+        }
+        self
+    }
+
+    /// Set the size of the dynamic candidate list used while building an HNSW index.
+    /// Must be called after `hnsw`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn efc(mut self, efc: u32) -> Self {
+        match &mut self.vector_index {
+            Some(VectorIndex::Hnsw { efc: e, .. }) => *e = Some(efc),
+            _ => self
+                .errors
+                .push("EFC only applies to HNSW indexes. Call `hnsw` before `efc`".to_string()),
+        }
+        self
+    }
+
+    /// Set the maximum number of connections per node of an HNSW index.
+    /// Must be called after `hnsw`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn m(mut self, m: u32) -> Self {
+        match &mut self.vector_index {
+            Some(VectorIndex::Hnsw { m: max, .. }) => *max = Some(m),
+            _ => self
+                .errors
+                .push("M only applies to HNSW indexes. Call `hnsw` before `m`".to_string()),
+        }
+        self
+    }
 }
 
 impl Buildable for DefineIndexStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE INDEX {}", self.index_name);
+        let mut query = format!("{} {}", define_prefix("INDEX", self.mode), self.index_name);
 
         if let Some(table) = &self.table {
             query = format!("{query} ON TABLE {table}");
@@ -263,6 +398,8 @@ impl Buildable for DefineIndexStatement {
         } else if let Some(search_analyzer) = &self.search_analyzer {
             let search_analyzer = search_analyzer.build();
             query = format!("{query} {search_analyzer}");
+        } else if let Some(vector_index) = &self.vector_index {
+            query = format!("{query} {vector_index}");
         }
         query += ";";
         query
@@ -295,7 +432,140 @@ impl Parametric for DefineIndexStatement {
 }
 
 impl Queryable for DefineIndexStatement {}
-impl Erroneous for DefineIndexStatement {}
+impl SingleStatement for DefineIndexStatement {}
+
+impl Erroneous for DefineIndexStatement {
+    fn get_errors(&self) -> ErrorList {
+        let mut errors = self.errors.clone();
+        if let Some(search_analyzer) = &self.search_analyzer {
+            errors.extend(search_analyzer.get_errors());
+        }
+        errors
+    }
+}
+
+/// Distance function used by a vector index
+#[derive(Debug, Clone, Copy)]
+pub enum VectorDistance {
+    /// Chebyshev distance
+    Chebyshev,
+    /// Cosine distance
+    Cosine,
+    /// Euclidean distance
+    Euclidean,
+    /// Hamming distance
+    Hamming,
+    /// Jaccard distance
+    Jaccard,
+    /// Manhattan distance
+    Manhattan,
+    /// Minkowski distance with the given order
+    Minkowski(u32),
+    /// Pearson distance
+    Pearson,
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorDistance::Chebyshev => write!(f, "CHEBYSHEV"),
+            VectorDistance::Cosine => write!(f, "COSINE"),
+            VectorDistance::Euclidean => write!(f, "EUCLIDEAN"),
+            VectorDistance::Hamming => write!(f, "HAMMING"),
+            VectorDistance::Jaccard => write!(f, "JACCARD"),
+            VectorDistance::Manhattan => write!(f, "MANHATTAN"),
+            VectorDistance::Minkowski(order) => write!(f, "MINKOWSKI {order}"),
+            VectorDistance::Pearson => write!(f, "PEARSON"),
+        }
+    }
+}
+
+/// Type of the elements of the vectors stored in a vector index
+#[derive(Debug, Clone, Copy)]
+pub enum VectorType {
+    /// 64 bit float
+    F64,
+    /// 32 bit float
+    F32,
+    /// 64 bit integer
+    I64,
+    /// 32 bit integer
+    I32,
+    /// 16 bit integer
+    I16,
+}
+
+impl Display for VectorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vector_type = match self {
+            VectorType::F64 => "F64",
+            VectorType::F32 => "F32",
+            VectorType::I64 => "I64",
+            VectorType::I32 => "I32",
+            VectorType::I16 => "I16",
+        };
+        write!(f, "{vector_type}")
+    }
+}
+
+enum VectorIndex {
+    Mtree {
+        dimension: u32,
+        distance: VectorDistance,
+        vector_type: VectorType,
+        capacity: Option<u32>,
+    },
+    #[cfg(feature = "surrealdb-v2")]
+    Hnsw {
+        dimension: u32,
+        distance: VectorDistance,
+        vector_type: VectorType,
+        efc: Option<u32>,
+        m: Option<u32>,
+    },
+}
+
+impl Display for VectorIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorIndex::Mtree {
+                dimension,
+                distance,
+                vector_type,
+                capacity,
+            } => {
+                write!(
+                    f,
+                    "MTREE DIMENSION {dimension} DIST {distance} TYPE {vector_type}"
+                )?;
+                if let Some(capacity) = capacity {
+                    write!(f, " CAPACITY {capacity}")?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "surrealdb-v2")]
+            VectorIndex::Hnsw {
+                dimension,
+                distance,
+                vector_type,
+                efc,
+                m,
+            } => {
+                write!(
+                    f,
+                    "HNSW DIMENSION {dimension} DIST {distance} TYPE {vector_type}"
+                )?;
+                if let Some(efc) = efc {
+                    write!(f, " EFC {efc}")?;
+                }
+                if let Some(m) = m {
+                    write!(f, " M {m}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
 
 /// Scoring for search
 pub enum Scoring {
@@ -560,6 +830,23 @@ mod tests {
         assert_eq!(query.get_bindings().len(), 1);
     }
 
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_index_statement_overwrite() {
+        let email = Field::new("email");
+
+        let query = define_index("userEmailIndex")
+            .overwrite()
+            .on_table("user")
+            .fields(email)
+            .unique();
+
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX OVERWRITE userEmailIndex ON TABLE user FIELDS email UNIQUE;"
+        );
+    }
+
     #[test]
     fn test_define_index_statement_single_column() {
         let email = Field::new("email");
@@ -613,4 +900,85 @@ mod tests {
         );
         assert_eq!(query.get_bindings().len(), 1);
     }
+
+    #[test]
+    fn test_define_index_statement_mtree() {
+        let embedding = Field::new("embedding");
+
+        let query = define_index("docEmbeddingIndex")
+            .on_table("document")
+            .fields(embedding)
+            .mtree(3, VectorDistance::Minkowski(2), VectorType::I32)
+            .capacity(40);
+
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX docEmbeddingIndex ON TABLE document FIELDS embedding MTREE DIMENSION 3 DIST MINKOWSKI 2 TYPE I32 CAPACITY 40;"
+        );
+        assert_eq!(
+            query.fine_tune_params(),
+            "DEFINE INDEX docEmbeddingIndex ON TABLE $_param_00000001 FIELDS embedding MTREE DIMENSION 3 DIST MINKOWSKI 2 TYPE I32 CAPACITY 40;"
+        );
+        assert_eq!(query.get_bindings().len(), 1);
+    }
+
+    #[test]
+    fn test_define_index_statement_capacity_without_mtree() {
+        let query = define_index("docEmbeddingIndex")
+            .on_table("document")
+            .fields(Field::new("embedding"))
+            .unique()
+            .capacity(40);
+
+        assert_eq!(
+            query.get_errors(),
+            vec!["CAPACITY only applies to MTREE indexes. Call `mtree` before `capacity`"]
+        );
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_index_statement_hnsw() {
+        let embedding = Field::new("embedding");
+
+        let query = define_index("docEmbeddingIndex")
+            .on_table("document")
+            .fields(embedding)
+            .hnsw(4, VectorDistance::Cosine, VectorType::F32)
+            .efc(150)
+            .m(12);
+
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX docEmbeddingIndex ON TABLE document FIELDS embedding HNSW DIMENSION 4 DIST COSINE TYPE F32 EFC 150 M 12;"
+        );
+        assert_eq!(query.get_bindings().len(), 1);
+        assert!(query.get_errors().is_empty());
+
+        let query = define_index("docEmbeddingIndex")
+            .on_table("document")
+            .fields(Field::new("embedding"))
+            .hnsw(4, VectorDistance::Cosine, VectorType::F32)
+            .capacity(40);
+        assert_eq!(query.get_errors().len(), 1);
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_index_statement_efc_and_m_without_hnsw() {
+        let query = define_index("docEmbeddingIndex")
+            .on_table("document")
+            .fields(Field::new("embedding"))
+            .mtree(4, VectorDistance::Cosine, VectorType::F32)
+            .efc(150)
+            .m(12);
+
+        assert_eq!(
+            query.get_errors(),
+            vec![
+                "EFC only applies to HNSW indexes. Call `hnsw` before `efc`",
+                "M only applies to HNSW indexes. Call `hnsw` before `m`",
+            ]
+        );
+    }
 }
@@ -1,7 +1,9 @@
 use crate::{
     statements::Permissions, BindingsList, Buildable, Erroneous, LiteralLike, Parametric,
-    Queryable, StrandLike, TableLike,
+    Queryable, SingleStatement, StrandLike, TableLike,
 };
+
+use super::{define_prefix, DefineMode};
 use std::fmt::{self, Display};
 
 /// DEFINE MODEL statement
@@ -17,6 +19,7 @@ use std::fmt::{self, Display};
 #[derive(Clone, Debug)]
 pub struct DefineModelStatement {
     model_name: String,
+    mode: Option<DefineMode>,
     version: String,
     comment: Option<String>,
     permissions_none: Option<bool>,
@@ -59,6 +62,7 @@ pub fn define_model(name: impl Into<ModelName>) -> DefineModelStatement {
         model_name: name.build(),
         version: String::new(),
         comment: None,
+        mode: None,
         permissions_none: None,
         permissions_full: None,
         permissions_for: vec![],
@@ -70,6 +74,22 @@ pub fn define_model(name: impl Into<ModelName>) -> DefineModelStatement {
 pub type ModelVersion = LiteralLike;
 
 impl DefineModelStatement {
+    /// Only define the model if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the model definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the version of the model.
     pub fn version(mut self, version: impl Into<ModelVersion>) -> Self {
         let version: ModelVersion = version.into();
@@ -159,6 +179,7 @@ impl DefineModelStatement {
 }
 
 impl Queryable for DefineModelStatement {}
+impl SingleStatement for DefineModelStatement {}
 
 impl Erroneous for DefineModelStatement {}
 
@@ -170,7 +191,11 @@ impl Parametric for DefineModelStatement {
 
 impl Buildable for DefineModelStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE MODEL ml::{}", &self.model_name);
+        let mut query = format!(
+            "{} ml::{}",
+            define_prefix("MODEL", self.mode),
+            &self.model_name
+        );
 
         if !self.version.is_empty() {
             query = format!("{query}<{version}>", version = self.version);
@@ -7,10 +7,12 @@
 use std::fmt::Display;
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::Namespace,
 };
 
+use super::{define_prefix, DefineMode};
+
 /// Define a new namespace .
 /// SurrealDB has a multi-tenancy model which allows you to scope databases to a namespace.
 /// There is no limit to the number of databases that can be in a namespace,
@@ -36,6 +38,7 @@ use crate::{
 pub fn define_namespace(namespace: impl Into<Namespace>) -> DefineNamespaceStatement {
     DefineNamespaceStatement {
         namespace: namespace.into().into(),
+        mode: None,
         bindings: vec![],
     }
 }
@@ -43,12 +46,35 @@ pub fn define_namespace(namespace: impl Into<Namespace>) -> DefineNamespaceState
 /// Define namespace
 pub struct DefineNamespaceStatement {
     namespace: String,
+    mode: Option<DefineMode>,
     bindings: BindingsList,
 }
 
+impl DefineNamespaceStatement {
+    /// Only define the namespace if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the namespace definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+}
+
 impl Buildable for DefineNamespaceStatement {
     fn build(&self) -> String {
-        format!("DEFINE NAMESPACE {};", self.namespace)
+        format!(
+            "{} {};",
+            define_prefix("NAMESPACE", self.mode),
+            self.namespace
+        )
     }
 }
 
@@ -65,6 +91,7 @@ impl Parametric for DefineNamespaceStatement {
 }
 
 impl Queryable for DefineNamespaceStatement {}
+impl SingleStatement for DefineNamespaceStatement {}
 impl Erroneous for DefineNamespaceStatement {}
 
 #[cfg(test)]
@@ -78,4 +105,13 @@ mod tests {
             "DEFINE NAMESPACE oyelowo;"
         );
     }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_namespace_if_not_exists() {
+        assert_eq!(
+            define_namespace("oyelowo").if_not_exists().build(),
+            "DEFINE NAMESPACE IF NOT EXISTS oyelowo;"
+        );
+    }
 }
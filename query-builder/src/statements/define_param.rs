@@ -7,9 +7,12 @@
 use std::{fmt::Display, ops::Deref};
 
 use crate::{
-    BindingsList, Buildable, Erroneous, ErrorList, Param, Parametric, Queryable, ValueLike,
+    BindingsList, Buildable, Erroneous, ErrorList, Param, Parametric, Queryable, SingleStatement,
+    ValueLike,
 };
 
+use super::{define_prefix, DefineMode};
+
 // DEFINE PARAM statement
 // The DEFINE PARAM statement allows you to define global (database-wide) parameters that are available to every client.
 //
@@ -57,6 +60,7 @@ pub fn define_param(param_name: impl Deref<Target = Param>) -> DefineParamStatem
     let define_param_statement = DefineParamStatement {
         name: param_name.to_string(),
         value: None,
+        mode: None,
         bindings: vec![],
         errors: vec![],
     };
@@ -68,12 +72,29 @@ pub struct DefineParamStatementBuilder(DefineParamStatement);
 /// Define param statement
 pub struct DefineParamStatement {
     name: String,
+    mode: Option<DefineMode>,
     value: Option<String>,
     bindings: BindingsList,
     errors: ErrorList,
 }
 
 impl DefineParamStatementBuilder {
+    /// Only define the param if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.0.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the param definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.0.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the value of the parameter.
     pub fn value(mut self, value: impl Into<ValueLike>) -> DefineParamStatement {
         let value: ValueLike = value.into();
@@ -86,7 +107,7 @@ impl DefineParamStatementBuilder {
 
 impl Buildable for DefineParamStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE PARAM {}", self.name);
+        let mut query = format!("{} {}", define_prefix("PARAM", self.mode), self.name);
 
         if let Some(value) = &self.value {
             query = format!("{query} VALUE {value}");
@@ -109,6 +130,7 @@ impl Erroneous for DefineParamStatement {
 }
 
 impl Queryable for DefineParamStatement {}
+impl SingleStatement for DefineParamStatement {}
 
 impl Display for DefineParamStatement {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
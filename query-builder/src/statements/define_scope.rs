@@ -17,11 +17,11 @@
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::{DurationLike, Scope},
 };
 
-use super::Subquery;
+use super::{define_prefix, DefineMode, Subquery};
 
 /// Define a new scope.
 /// Setting scope access allows SurrealDB to operate as a web database.
@@ -63,6 +63,7 @@ pub fn define_scope(scope_name: impl Into<Scope>) -> DefineScopeStatement {
     DefineScopeStatement {
         name,
         duration: None,
+        mode: None,
         signup_expression: None,
         signin_expression: None,
         bindings: vec![binding_scope_name],
@@ -72,6 +73,7 @@ pub fn define_scope(scope_name: impl Into<Scope>) -> DefineScopeStatement {
 /// Define the API for the Scope builder
 pub struct DefineScopeStatement {
     name: String,
+    mode: Option<DefineMode>,
     duration: Option<String>,
     signup_expression: Option<String>,
     signin_expression: Option<String>,
@@ -79,6 +81,22 @@ pub struct DefineScopeStatement {
 }
 
 impl DefineScopeStatement {
+    /// Only define the scope if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the scope definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set the session duration
     pub fn session(mut self, duration: impl Into<DurationLike>) -> Self {
         let duration: DurationLike = duration.into();
@@ -106,7 +124,7 @@ impl DefineScopeStatement {
 
 impl Buildable for DefineScopeStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE SCOPE {}", self.name);
+        let mut query = format!("{} {}", define_prefix("SCOPE", self.mode), self.name);
 
         if let Some(session_duration) = &self.duration {
             query = format!("{query} SESSION {session_duration}");
@@ -138,6 +156,7 @@ impl Parametric for DefineScopeStatement {
 }
 
 impl Queryable for DefineScopeStatement {}
+impl SingleStatement for DefineScopeStatement {}
 
 impl Erroneous for DefineScopeStatement {}
 
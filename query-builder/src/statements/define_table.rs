@@ -8,9 +8,15 @@ use std::fmt::{self, Display};
 
 use crate::{
     statements::{for_permission::Permissions, select::SelectStatement},
-    BindingsList, Buildable, Erroneous, Parametric, Queryable, Table,
+    BindingsList, Buildable, DurationLike, Erroneous, Parametric, Queryable, SingleStatement,
+    Table, ToRaw,
 };
 
+#[cfg(feature = "surrealdb-v2")]
+use crate::Tables;
+
+use super::{define_prefix, DefineMode};
+
 // DEFINE TABLE statement
 // The DEFINE TABLE statement allows you to declare your table by name, enabling you to apply strict controls to a table's schema by making it SCHEMAFULL, create a foreign table view, and set permissions specifying what operations can be performed on the field.
 //
@@ -21,11 +27,13 @@ use crate::{
 // DEFINE TABLE @name
 // 	[ DROP ]
 // 	[ SCHEMAFULL | SCHEMALESS ]
+// 	[ TYPE [ ANY | NORMAL | RELATION [ IN | FROM ] @table [ OUT | TO ] @table [ ENFORCED ] ] ]
 // 	[ AS SELECT @projections
 // 		FROM @tables
 // 		[ WHERE @condition ]
 // 		[ GROUP [ BY ] @groups ]
 // 	]
+// 	[ CHANGEFEED @duration [ INCLUDE ORIGINAL ] ]
 // 	[ PERMISSIONS [ NONE | FULL
 // 		| FOR select @expression
 // 		| FOR create @expression
@@ -36,10 +44,15 @@ use crate::{
 /// Define the API for the Table builder
 pub struct DefineTableStatement {
     table: String,
+    mode: Option<DefineMode>,
     drop: Option<bool>,
     flexible: Option<bool>,
     schema_type: Option<SchemaType>,
+    table_type: Option<TableType>,
+    enforced: bool,
     as_: Option<String>,
+    changefeed: Option<String>,
+    changefeed_include_original: bool,
     permissions_none: Option<bool>,
     permissions_full: Option<bool>,
     permissions_for: Vec<String>,
@@ -94,9 +107,14 @@ pub fn define_table(table: impl Into<Table>) -> DefineTableStatement {
     DefineTableStatement {
         table: table.to_string(),
         drop: None,
+        mode: None,
         flexible: None,
         schema_type: None,
+        table_type: None,
+        enforced: false,
         as_: None,
+        changefeed: None,
+        changefeed_include_original: false,
         permissions_none: None,
         permissions_full: None,
         permissions_for: vec![],
@@ -105,6 +123,22 @@ pub fn define_table(table: impl Into<Table>) -> DefineTableStatement {
 }
 
 impl DefineTableStatement {
+    /// Only define the table if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the table definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Drop the table if it exists and create a new one with the same name.
     pub fn drop(mut self) -> Self {
         self.drop = Some(true);
@@ -129,6 +163,63 @@ impl DefineTableStatement {
         self
     }
 
+    /// Allow the table to hold both normal records and relations. Emits `TYPE ANY`.
+    /// Table types require SurrealDB 2.0.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn type_any(mut self) -> Self {
+        self.table_type = Some(TableType::Any);
+        self
+    }
+
+    /// Only allow normal records in the table, not relations. Emits `TYPE NORMAL`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn type_normal(mut self) -> Self {
+        self.table_type = Some(TableType::Normal);
+        self
+    }
+
+    /// Make the table a relation (edge) table whose records can only connect
+    /// records of the `in_` tables to records of the `out` tables.
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::define_table};
+    /// let student = Table::from("student");
+    /// let book = Table::from("book");
+    /// let blog = Table::from("blog");
+    ///
+    /// let statement = define_table("writes")
+    ///     .schemafull()
+    ///     .type_relation(student, [book, blog])
+    ///     .enforced();
+    ///
+    /// assert_eq!(
+    ///     statement.build(),
+    ///     "DEFINE TABLE writes SCHEMAFULL TYPE RELATION IN student OUT book|blog ENFORCED;"
+    /// );
+    /// ```
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn type_relation(mut self, in_: impl Into<Tables>, out: impl Into<Tables>) -> Self {
+        let in_: Vec<Table> = in_.into().into();
+        let out: Vec<Table> = out.into().into();
+        self.table_type = Some(TableType::Relation { in_, out });
+        self
+    }
+
+    /// Make sure the records on both ends of a relation exist when it is created.
+    /// Only takes effect together with `type_relation`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn enforced(mut self) -> Self {
+        self.enforced = true;
+        self
+    }
+
     /// Select from existing table.
     ///
     /// Examples:
@@ -163,6 +254,48 @@ impl DefineTableStatement {
         self
     }
 
+    /// Enable the change feed for the table. Changes to the records of the table
+    /// are kept for the given duration and can be read with the SHOW CHANGES statement.
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::define_table};
+    /// # use std::time::Duration;
+    /// let statement = define_table("reading").changefeed(Duration::from_secs(60 * 60));
+    ///
+    /// assert_eq!(statement.build(), "DEFINE TABLE reading CHANGEFEED 1h;");
+    /// ```
+    pub fn changefeed(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.changefeed = Some(duration.to_raw().build());
+        self
+    }
+
+    /// Include the original record before the change in the change feed of the table.
+    /// Only takes effect together with `changefeed`.
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::define_table};
+    /// # use std::time::Duration;
+    /// let statement = define_table("reading")
+    ///     .changefeed(Duration::from_secs(60 * 60 * 24))
+    ///     .include_original();
+    ///
+    /// assert_eq!(
+    ///     statement.build(),
+    ///     "DEFINE TABLE reading CHANGEFEED 1d INCLUDE ORIGINAL;"
+    /// );
+    /// ```
+    pub fn include_original(mut self) -> Self {
+        self.changefeed_include_original = true;
+        self
+    }
+
     /// Set permission as NONE
     pub fn permissions_none(mut self) -> Self {
         self.permissions_none = Some(true);
@@ -237,11 +370,13 @@ impl DefineTableStatement {
 // DEFINE TABLE @name
 // 	[ DROP ]
 // 	[ SCHEMAFULL | SCHEMALESS ]
+// 	[ TYPE [ ANY | NORMAL | RELATION [ IN | FROM ] @table [ OUT | TO ] @table [ ENFORCED ] ] ]
 // 	[ AS SELECT @projections
 // 		FROM @tables
 // 		[ WHERE @condition ]
 // 		[ GROUP [ BY ] @groups ]
 // 	]
+// 	[ CHANGEFEED @duration [ INCLUDE ORIGINAL ] ]
 // 	[ PERMISSIONS [ NONE | FULL
 // 		| FOR select @expression
 // 		| FOR create @expression
@@ -250,7 +385,7 @@ impl DefineTableStatement {
 // 	] ]
 impl Buildable for DefineTableStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE TABLE {}", &self.table);
+        let mut query = format!("{} {}", define_prefix("TABLE", self.mode), &self.table);
 
         if self.drop.unwrap_or_default() {
             query = format!("{query} DROP");
@@ -270,10 +405,47 @@ impl Buildable for DefineTableStatement {
             None => {}
         };
 
+        match &self.table_type {
+            Some(TableType::Any) => {
+                query = format!("{query} TYPE ANY");
+            }
+            Some(TableType::Normal) => {
+                query = format!("{query} TYPE NORMAL");
+            }
+            Some(TableType::Relation { in_, out }) => {
+                let join = |tables: &[Table]| {
+                    tables
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("|")
+                };
+                query = format!("{query} TYPE RELATION");
+                if !in_.is_empty() {
+                    query = format!("{query} IN {}", join(in_));
+                }
+                if !out.is_empty() {
+                    query = format!("{query} OUT {}", join(out));
+                }
+                if self.enforced {
+                    query = format!("{query} ENFORCED");
+                }
+            }
+            None => {}
+        };
+
         if let Some(select_statement) = &self.as_ {
             query = format!("{query} AS \n\t{}", select_statement.trim_end_matches(';'));
         }
 
+        if let Some(duration) = &self.changefeed {
+            query = format!("{query} CHANGEFEED {duration}");
+
+            if self.changefeed_include_original {
+                query = format!("{query} INCLUDE ORIGINAL");
+            }
+        }
+
         if let Some(true) = self.permissions_none {
             query = format!("{query} PERMISSIONS NONE");
         } else if let Some(true) = self.permissions_full {
@@ -294,6 +466,7 @@ impl Display for DefineTableStatement {
 }
 
 impl Queryable for DefineTableStatement {}
+impl SingleStatement for DefineTableStatement {}
 impl Erroneous for DefineTableStatement {}
 
 impl Parametric for DefineTableStatement {
@@ -307,6 +480,13 @@ enum SchemaType {
     Schemaless,
 }
 
+#[cfg_attr(not(feature = "surrealdb-v2"), allow(dead_code))]
+enum TableType {
+    Any,
+    Normal,
+    Relation { in_: Vec<Table>, out: Vec<Table> },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +495,7 @@ mod tests {
         statements::{for_permission, select},
         *,
     };
+    use std::time::Duration;
     use surrealdb::sql;
     use CrudType::*;
 
@@ -352,6 +533,40 @@ mod tests {
         assert_eq!(statement.get_bindings().len(), 0);
     }
 
+    #[test]
+    fn test_define_statement_changefeed() {
+        let user = Table::from("user");
+        let statement = define_table(user)
+            .schemafull()
+            .changefeed(Duration::from_secs(60 * 60 * 24 * 7))
+            .include_original()
+            .permissions_full();
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE TABLE user SCHEMAFULL CHANGEFEED 1w INCLUDE ORIGINAL PERMISSIONS FULL;"
+        );
+        assert_eq!(statement.get_bindings().len(), 0);
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_statement_if_not_exists_and_overwrite() {
+        let user = Table::from("user");
+
+        assert_eq!(
+            define_table(user.clone())
+                .if_not_exists()
+                .schemafull()
+                .build(),
+            "DEFINE TABLE IF NOT EXISTS user SCHEMAFULL;"
+        );
+        assert_eq!(
+            define_table(user).overwrite().schemafull().build(),
+            "DEFINE TABLE OVERWRITE user SCHEMAFULL;"
+        );
+    }
+
     #[test]
     fn test_define_statement_multiple() {
         let name = Field::new("name");
@@ -398,4 +613,35 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 8);
     }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_statement_type_relation_enforced() {
+        let statement = define_table("writes")
+            .schemafull()
+            .type_relation(
+                Table::from("student"),
+                [Table::from("book"), Table::from("blog")],
+            )
+            .enforced()
+            .permissions_full();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE TABLE writes SCHEMAFULL TYPE RELATION IN student OUT book|blog ENFORCED PERMISSIONS FULL;"
+        );
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_define_statement_type_normal_and_any() {
+        assert_eq!(
+            define_table("user").type_normal().build(),
+            "DEFINE TABLE user TYPE NORMAL;"
+        );
+        assert_eq!(
+            define_table("user").type_any().enforced().build(),
+            "DEFINE TABLE user TYPE ANY;"
+        );
+    }
 }
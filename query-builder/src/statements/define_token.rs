@@ -9,10 +9,12 @@ use std::fmt::{self, Display};
 use surrealdb::sql;
 
 use crate::{
-    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::{Idiomx, Scope, TokenTarget, TokenType},
 };
 
+use super::{define_prefix, DefineMode};
+
 // DEFINE TOKEN statement
 // SurrealDB can work with third-party OAuth providers. Let's say that your provider issues your service a JWT once it's authenticated. By using the DEFINE TOKEN statement, you can set the public key needed to verify a JWT's authenticity.
 //
@@ -42,6 +44,7 @@ use crate::{
 /// Define the API for the Token builder
 pub struct DefineTokenStatement {
     name: String,
+    mode: Option<DefineMode>,
     token_type: Option<TokenType>,
     value: Option<String>,
     target: Option<TokenTarget>,
@@ -90,6 +93,7 @@ pub fn define_token(token_name: impl Into<Idiomx>) -> DefineTokenStatement {
     DefineTokenStatement {
         name: token_name.to_string(),
         token_type: None,
+        mode: None,
         value: None,
         target: None,
         bindings: vec![],
@@ -97,6 +101,22 @@ pub fn define_token(token_name: impl Into<Idiomx>) -> DefineTokenStatement {
 }
 
 impl DefineTokenStatement {
+    /// Only define the token if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the token definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Define the token for the namespace
     pub fn on_namespace(mut self) -> Self {
         self.target = Some(TokenTarget::Namespace);
@@ -132,7 +152,7 @@ impl DefineTokenStatement {
 
 impl Buildable for DefineTokenStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE TOKEN {}", self.name);
+        let mut query = format!("{} {}", define_prefix("TOKEN", self.mode), self.name);
 
         if let Some(target) = &self.target {
             query = format!("{query} ON {target}");
@@ -164,6 +184,7 @@ impl Parametric for DefineTokenStatement {
 }
 
 impl Queryable for DefineTokenStatement {}
+impl SingleStatement for DefineTokenStatement {}
 
 impl Erroneous for DefineTokenStatement {}
 
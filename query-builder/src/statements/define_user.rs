@@ -7,10 +7,12 @@
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::Idiomx,
 };
 
+use super::{define_prefix, DefineMode};
+
 pub struct Password(pub(crate) String);
 
 impl Password {
@@ -133,6 +135,7 @@ pub enum UserCredential {
 /// Define a new database user.
 pub struct DefineUserStatement {
     name: String,
+    mode: Option<DefineMode>,
     user_type: Option<UserType>,
     credential: Option<UserCredential>,
     role: Option<UserRole>,
@@ -146,12 +149,29 @@ impl DefineUserStatement {
         Self {
             name: binding.get_param_dollarised(),
             user_type: None,
+            mode: None,
             credential: None,
             role: None,
             bindings: vec![binding],
         }
     }
 
+    /// Only define the user if it does not exist yet. Emits `IF NOT EXISTS`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn if_not_exists(mut self) -> Self {
+        self.mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replace the user definition if it already exists. Emits `OVERWRITE`.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    pub fn overwrite(mut self) -> Self {
+        self.mode = Some(DefineMode::Overwrite);
+        self
+    }
+
     /// Set user on root
     pub fn on_root(mut self) -> Self {
         self.user_type = Some(UserType::Root);
@@ -199,7 +219,7 @@ impl DefineUserStatement {
 
 impl Buildable for DefineUserStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE USER {}", self.name);
+        let mut query = format!("{} {}", define_prefix("USER", self.mode), self.name);
 
         if let Some(user_type) = &self.user_type {
             query.push_str(&format!(" ON {user_type}"));
@@ -236,6 +256,7 @@ impl Parametric for DefineUserStatement {
 }
 
 impl Queryable for DefineUserStatement {}
+impl SingleStatement for DefineUserStatement {}
 
 impl Erroneous for DefineUserStatement {}
 
@@ -9,7 +9,7 @@ use std::marker::PhantomData;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Model, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Model, Parametric, Queryable, SingleStatement},
     types::{DurationLike, Filter, ReturnType},
     Binding, Conditional, ErrorList, ReturnableDefault, ReturnableStandard, ToRaw,
 };
@@ -131,6 +131,7 @@ where
 }
 
 impl<T> Queryable for DeleteStatement<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> SingleStatement for DeleteStatement<T> where T: Serialize + DeserializeOwned + Model {}
 
 impl<T> Erroneous for DeleteStatement<T>
 where
@@ -349,6 +350,7 @@ where
 }
 
 impl<T> Queryable for DeleteStatementMini<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> SingleStatement for DeleteStatementMini<T> where T: Serialize + DeserializeOwned + Model {}
 
 impl<T> ReturnableDefault<T> for DeleteStatementMini<T> where T: Serialize + DeserializeOwned + Model
 {}
@@ -8,7 +8,7 @@ use std::fmt;
 
 use crate::{
     expression::Expression,
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     Block, ErrorList, Param,
 };
 
@@ -188,6 +188,7 @@ impl Erroneous for ForLoopStatement {
     }
 }
 impl Queryable for ForLoopStatement {}
+impl SingleStatement for ForLoopStatement {}
 
 impl fmt::Display for ForLoopStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
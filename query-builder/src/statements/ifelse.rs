@@ -17,7 +17,9 @@ use std::fmt;
 
 use crate::{
     expression::Expression,
-    traits::{BindingsList, Buildable, Conditional, Erroneous, Parametric, Queryable},
+    traits::{
+        BindingsList, Buildable, Conditional, Erroneous, Parametric, Queryable, SingleStatement,
+    },
     types::Filter,
     QueryChain,
 };
@@ -310,6 +312,7 @@ impl Buildable for IfElseStatement {
 }
 
 impl Queryable for IfElseStatement {}
+impl SingleStatement for IfElseStatement {}
 
 impl fmt::Display for IfElseStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -4,11 +4,14 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use surrealdb::{Connection, Surreal};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
-    Scope, Table,
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
+    Runnable, Scope, SurrealOrmError, SurrealOrmResult, Table,
 };
 
 /// Creates statement for INFO for KV(i.e system), NAMESPACE, DATABASE, SCOPE, or TABLE.
@@ -119,6 +122,81 @@ impl InfoStatementInit {
     }
 }
 
+/// Definitions of resources keyed by their names e.g
+/// `{ "email": "DEFINE FIELD email ON user TYPE string" }`.
+pub type Definitions = BTreeMap<String, String>;
+
+/// The result of `INFO FOR KV`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct KvInfo {
+    /// The namespaces defined in the system.
+    pub namespaces: Definitions,
+    /// The root users defined in the system.
+    pub users: Definitions,
+}
+
+/// The result of `INFO FOR NS`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct NamespaceInfo {
+    /// The databases defined in the namespace.
+    pub databases: Definitions,
+    /// The tokens defined in the namespace.
+    pub tokens: Definitions,
+    /// The users defined in the namespace.
+    pub users: Definitions,
+}
+
+/// The result of `INFO FOR DB`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct DatabaseInfo {
+    /// The analyzers defined in the database.
+    pub analyzers: Definitions,
+    /// The functions defined in the database.
+    pub functions: Definitions,
+    /// The machine learning models defined in the database.
+    pub models: Definitions,
+    /// The params defined in the database.
+    pub params: Definitions,
+    /// The scopes defined in the database.
+    pub scopes: Definitions,
+    /// The access methods defined in the database.
+    /// Not returned by databases that predate access methods.
+    pub accesses: Definitions,
+    /// The tables defined in the database.
+    pub tables: Definitions,
+    /// The tokens defined in the database.
+    pub tokens: Definitions,
+    /// The users defined in the database.
+    pub users: Definitions,
+}
+
+/// The result of `INFO FOR SCOPE`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct ScopeInfo {
+    /// The tokens defined in the scope.
+    pub tokens: Definitions,
+}
+
+/// The result of `INFO FOR TABLE`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct TableInfo {
+    /// The events defined on the table.
+    pub events: Definitions,
+    /// The fields defined on the table.
+    pub fields: Definitions,
+    /// The indexes defined on the table.
+    pub indexes: Definitions,
+    /// The foreign tables i.e views defined on the table.
+    pub tables: Definitions,
+    /// The live queries running on the table.
+    pub lives: Definitions,
+}
+
 /// Information statement builder
 pub struct InfoStatement(InfoStatementInit);
 
@@ -128,7 +206,32 @@ impl From<InfoStatementInit> for InfoStatement {
     }
 }
 
+impl InfoStatement {
+    /// Runs the statement against the database and returns the information as `T`
+    /// e.g `KvInfo`, `NamespaceInfo`, `DatabaseInfo`, `ScopeInfo` or `TableInfo`.
+    /// Resources that are not defined are returned as empty maps.
+    ///
+    /// Examples
+    /// ```rust, ignore
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{info_for, TableInfo}};
+    ///
+    /// let info = info_for().table("user").get_info::<TableInfo>(db.clone()).await?;
+    /// assert!(info.fields.contains_key("email"));
+    /// assert!(info.indexes.contains_key("email_idx"));
+    /// ```
+    pub async fn get_info<T>(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.get_data::<T>(db)
+            .await?
+            .ok_or_else(|| SurrealOrmError::RecordNotFound(self.build()))
+    }
+}
+
 impl Queryable for InfoStatement {}
+impl SingleStatement for InfoStatement {}
 
 impl Erroneous for InfoStatement {}
 
@@ -190,4 +293,50 @@ mod tests {
         let statement = info_for().table("test_table").build();
         assert_eq!(statement, "INFO FOR TABLE test_table;");
     }
+
+    #[test]
+    fn test_table_info_deserializes_definitions() {
+        let info: TableInfo = serde_json::from_value(serde_json::json!({
+            "events": {},
+            "fields": {
+                "email": "DEFINE FIELD email ON user TYPE string PERMISSIONS FULL"
+            },
+            "indexes": {
+                "email_idx": "DEFINE INDEX email_idx ON user FIELDS email UNIQUE"
+            },
+            "tables": {},
+        }))
+        .unwrap();
+
+        assert!(info.events.is_empty());
+        assert!(info.lives.is_empty());
+        assert_eq!(
+            info.fields.get("email").map(String::as_str),
+            Some("DEFINE FIELD email ON user TYPE string PERMISSIONS FULL")
+        );
+        assert_eq!(
+            info.indexes.get("email_idx").map(String::as_str),
+            Some("DEFINE INDEX email_idx ON user FIELDS email UNIQUE")
+        );
+    }
+
+    #[test]
+    fn test_db_info_defaults_missing_resources() {
+        let info: DatabaseInfo = serde_json::from_value(serde_json::json!({
+            "analyzers": {},
+            "functions": {},
+            "params": {},
+            "scopes": {},
+            "tables": {
+                "user": "DEFINE TABLE user TYPE NORMAL SCHEMAFULL PERMISSIONS NONE"
+            },
+            "tokens": {},
+            "users": {},
+        }))
+        .unwrap();
+
+        assert!(info.accesses.is_empty());
+        assert!(info.models.is_empty());
+        assert_eq!(info.tables.keys().collect::<Vec<_>>(), vec!["user"]);
+    }
 }
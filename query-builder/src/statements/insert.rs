@@ -17,7 +17,9 @@ use serde::{de::DeserializeOwned, Serialize};
 use surrealdb::sql;
 
 use crate::{
-    traits::{Binding, BindingsList, Buildable, Erroneous, Node, Parametric, Queryable},
+    traits::{
+        Binding, BindingsList, Buildable, Erroneous, Node, Parametric, Queryable, SingleStatement,
+    },
     types::Updateables,
     ErrorList, ReturnType, ReturnableDefault, ReturnableStandard,
 };
@@ -120,6 +122,7 @@ where
 }
 
 impl<T> Queryable for InsertStatement<T> where T: Serialize + DeserializeOwned + Node {}
+impl<T> SingleStatement for InsertStatement<T> where T: Serialize + DeserializeOwned + Node {}
 impl<T> Erroneous for InsertStatement<T>
 where
     T: Serialize + DeserializeOwned + Node,
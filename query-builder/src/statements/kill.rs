@@ -0,0 +1,102 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Statement syntax
+// KILL @id;
+
+use std::fmt::Display;
+
+use crate::{
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
+    types::UuidLike,
+    ErrorList,
+};
+
+/// Creates a KILL statement which stops a running live query.
+///
+/// # Arguments
+///
+/// * `live_id` - The UUID of the live query to kill. Can be a `sql::Uuid`, `uuid::Uuid`,
+///     a field or a param.
+///
+/// Examples
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::kill};
+///
+/// let live_id = sql::Uuid::new_v4();
+/// let statement = kill(live_id);
+///
+/// assert_eq!(statement.to_raw().build(), format!("KILL {live_id};"));
+/// ```
+pub fn kill(live_id: impl Into<UuidLike>) -> KillStatement {
+    KillStatement {
+        live_id: live_id.into(),
+    }
+}
+
+/// Kill statement builder
+pub struct KillStatement {
+    live_id: UuidLike,
+}
+
+impl Buildable for KillStatement {
+    fn build(&self) -> String {
+        format!("KILL {};", self.live_id.build())
+    }
+}
+
+impl Queryable for KillStatement {}
+impl SingleStatement for KillStatement {}
+
+impl Erroneous for KillStatement {
+    fn get_errors(&self) -> ErrorList {
+        self.live_id.get_errors()
+    }
+}
+
+impl Parametric for KillStatement {
+    fn get_bindings(&self) -> BindingsList {
+        self.live_id.get_bindings()
+    }
+}
+
+impl Display for KillStatement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Param, ToRaw};
+    use surrealdb::sql;
+
+    #[test]
+    fn test_kill_statement() {
+        let live_id = sql::Uuid::from(
+            uuid::Uuid::parse_str("b8a9e5d4-8c3f-4a57-9d2e-5f6a7b8c9d0e").unwrap(),
+        );
+        let statement = kill(live_id);
+
+        assert_eq!(statement.fine_tune_params(), "KILL $_param_00000001;");
+        assert_eq!(
+            statement.to_raw().build(),
+            "KILL 'b8a9e5d4-8c3f-4a57-9d2e-5f6a7b8c9d0e';"
+        );
+        assert_eq!(statement.get_bindings().len(), 1);
+    }
+
+    #[test]
+    fn test_kill_statement_with_param() {
+        let statement = kill(Param::new("live_id"));
+
+        assert_eq!(statement.build(), "KILL $live_id;");
+        assert_eq!(statement.get_bindings().len(), 0);
+    }
+}
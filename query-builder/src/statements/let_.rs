@@ -7,7 +7,7 @@
 use std::fmt::Display;
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::expression::Expression,
     Clause, Conditional, Operatable, Operation, Param, SchemaGetter, ValueLike,
 };
@@ -181,7 +181,9 @@ impl Parametric for &LetStatement {
 }
 
 impl Queryable for LetStatement {}
+impl SingleStatement for LetStatement {}
 impl Queryable for &LetStatement {}
+impl SingleStatement for &LetStatement {}
 impl Erroneous for LetStatement {}
 impl Erroneous for &LetStatement {}
 
@@ -4,11 +4,20 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use std::fmt;
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{Stream, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use surrealdb::{sql, Connection, Surreal};
 
 use crate::{
     statements::SelectStatement,
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
+    Model, Runnable, SurrealId, SurrealOrmError, SurrealOrmResult,
 };
 
 /// Creates a LIVE SELECT statement builder.
@@ -31,7 +40,190 @@ pub struct LiveSelectStatement {
     select: SelectStatement,
 }
 
+impl LiveSelectStatement {
+    /// Runs the LIVE SELECT statement and returns a handle to the running live query.
+    /// The handle exposes the live query UUID and is itself a stream of typed notifications
+    /// for every record created, updated or deleted in the selected table.
+    ///
+    /// The live query is killed by the database driver once the handle is dropped,
+    /// so it can simply go out of scope when it is no longer needed, or be killed explicitly
+    /// with [`LiveQuery::kill`]. The `kill` statement is for ids captured elsewhere, as the
+    /// driver would otherwise kill the live query a second time when the handle is dropped.
+    ///
+    /// Examples
+    /// ```rust, ignore
+    /// # use surreal_query_builder as surreal_orm;
+    /// use futures::StreamExt;
+    /// use surreal_orm::{*, statements::{live, select, Notification}};
+    ///
+    /// let mut stream = live(select(All).from(User::table()))
+    ///     .stream::<User>(db.clone())
+    ///     .await?;
+    /// println!("listening with live query {}", stream.id());
+    ///
+    /// while let Some(notification) = stream.next().await {
+    ///     match notification? {
+    ///         Notification::Create { id, data } => println!("created {id}: {data:?}"),
+    ///         Notification::Update { id, data } => println!("updated {id}: {data:?}"),
+    ///         Notification::Delete { id } => println!("deleted {id}"),
+    ///     }
+    /// }
+    /// ```
+    pub async fn stream<T>(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<LiveQuery<T>>
+    where
+        T: Model + Serialize + DeserializeOwned + Unpin + Send + 'static,
+    {
+        let mut response = self.run(db).await?;
+        // The stream is taken first, as it reports the error of the LIVE statement
+        // from its result, which taking the live query id consumes.
+        let stream = response
+            .stream::<surrealdb::Notification<NotificationPayload<T>>>(0)
+            .map_err(SurrealOrmError::query_run)?
+            .map(|notification| {
+                let notification = notification.map_err(SurrealOrmError::Deserialization)?;
+                Notification::try_from_raw(notification.action, notification.data)
+            });
+        let id = get_live_id(&mut response)?;
+
+        Ok(LiveQuery {
+            id,
+            stream: Box::pin(stream),
+        })
+    }
+}
+
+fn get_live_id(response: &mut surrealdb::Response) -> SurrealOrmResult<sql::Uuid> {
+    let value = response
+        .take::<sql::Value>(0)
+        .map_err(SurrealOrmError::Deserialization)?;
+
+    match value {
+        sql::Value::Uuid(id) => Ok(id),
+        value => Err(SurrealOrmError::LiveQuery(format!(
+            "Expected the live query id to be a uuid but got {value}"
+        ))),
+    }
+}
+
+/// A handle to a running live query. It is a stream of typed notifications
+/// and also carries the UUID of the live query for tracking and killing it.
+pub struct LiveQuery<T: Model> {
+    id: sql::Uuid,
+    stream: Pin<Box<dyn Stream<Item = SurrealOrmResult<Notification<T>>> + Send>>,
+}
+
+impl<T> LiveQuery<T>
+where
+    T: Model,
+{
+    /// Returns the UUID of the live query.
+    pub fn id(&self) -> sql::Uuid {
+        self.id
+    }
+
+    /// Kills the live query. The stream is dropped, which makes the database driver
+    /// send the KILL statement for the live query id in the background.
+    pub fn kill(self) {
+        drop(self);
+    }
+}
+
+impl<T> Stream for LiveQuery<T>
+where
+    T: Model,
+{
+    type Item = SurrealOrmResult<Notification<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx)
+    }
+}
+
+/// A typed notification received from a live query.
+#[derive(Debug, Clone)]
+pub enum Notification<T: Model> {
+    /// A record was created in the table
+    Create {
+        /// The id of the created record
+        id: SurrealId<T, sql::Id>,
+        /// The created record
+        data: T,
+    },
+    /// A record was updated in the table
+    Update {
+        /// The id of the updated record
+        id: SurrealId<T, sql::Id>,
+        /// The record after the update
+        data: T,
+    },
+    /// A record was deleted from the table
+    Delete {
+        /// The id of the deleted record
+        id: SurrealId<T, sql::Id>,
+    },
+}
+
+impl<T> Notification<T>
+where
+    T: Model,
+{
+    /// Returns the id of the record the notification is about.
+    pub fn id(&self) -> &SurrealId<T, sql::Id> {
+        match self {
+            Notification::Create { id, .. }
+            | Notification::Update { id, .. }
+            | Notification::Delete { id } => id,
+        }
+    }
+
+    /// Returns the record data for create and update notifications.
+    pub fn data(&self) -> Option<&T> {
+        match self {
+            Notification::Create { data, .. } | Notification::Update { data, .. } => Some(data),
+            Notification::Delete { .. } => None,
+        }
+    }
+
+    fn try_from_raw(
+        action: surrealdb::Action,
+        payload: NotificationPayload<T>,
+    ) -> SurrealOrmResult<Self> {
+        let thing = match &payload {
+            NotificationPayload::Record(record) => record.get_id_as_thing(),
+            NotificationPayload::Id(thing) => thing.clone(),
+        };
+        let id = SurrealId::try_from(thing)?;
+
+        let notification = match (action, payload) {
+            (surrealdb::Action::Create, NotificationPayload::Record(data)) => {
+                Notification::Create { id, data }
+            }
+            (surrealdb::Action::Update, NotificationPayload::Record(data)) => {
+                Notification::Update { id, data }
+            }
+            (surrealdb::Action::Delete, _) => Notification::Delete { id },
+            (action, _) => {
+                return Err(SurrealOrmError::LiveQuery(format!(
+                    "Unexpected {action:?} notification for record {id}"
+                )))
+            }
+        };
+
+        Ok(notification)
+    }
+}
+
+/// Depending on the action and the database version, a notification either carries
+/// the full record or only the record id (e.g for deletions).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum NotificationPayload<T> {
+    Record(T),
+    Id(sql::Thing),
+}
+
 impl Queryable for LiveSelectStatement {}
+impl SingleStatement for LiveSelectStatement {}
 
 impl Erroneous for LiveSelectStatement {}
 
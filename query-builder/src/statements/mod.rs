@@ -10,6 +10,8 @@ pub(crate) mod break_;
 pub(crate) mod continue_;
 pub(crate) mod create;
 pub(crate) mod create_v2;
+#[cfg(feature = "surrealdb-v2")]
+pub(crate) mod define_access;
 pub(crate) mod define_analyzer;
 pub(crate) mod define_database;
 pub(crate) mod define_event;
@@ -29,9 +31,14 @@ pub(crate) mod for_permission;
 pub(crate) mod ifelse;
 pub(crate) mod info;
 pub(crate) mod insert;
+pub(crate) mod kill;
 pub(crate) mod let_;
 pub(crate) mod live_select;
+#[cfg(feature = "surrealdb-v2")]
+pub(crate) mod rebuild_index;
 pub(crate) mod relate;
+#[cfg(feature = "surrealdb-v2")]
+pub(crate) mod remove_access;
 pub(crate) mod remove_analyzer;
 pub(crate) mod remove_database;
 pub(crate) mod remove_event;
@@ -48,6 +55,8 @@ pub(crate) mod remove_token;
 pub(crate) mod remove_user;
 pub(crate) mod return_;
 pub(crate) mod select;
+pub(crate) mod select_explain;
+pub(crate) mod select_pagination;
 pub(crate) mod select_surreal_id_range;
 pub(crate) mod show;
 pub(crate) mod sleep;
@@ -55,7 +64,10 @@ pub(crate) mod subquery;
 pub(crate) mod throw;
 pub(crate) mod transaction;
 pub(crate) mod update;
+#[cfg(feature = "surrealdb-v2")]
+pub(crate) mod upsert;
 pub(crate) mod use_;
+pub(crate) mod utils_batch;
 pub(crate) mod utils_block;
 pub(crate) mod utils_chain;
 
@@ -63,6 +75,9 @@ pub use break_::{break_, BreakStatement};
 pub use continue_::{continue_, ContinueStatement};
 pub use create::{create, create_only, CreateStatement};
 pub use create_v2::{create_v2, CreateStatementV2};
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use define_access::{define_access, DefineAccessStatement};
 pub use define_analyzer::{
     define_analyzer, AnalyzerFilter, DefineAnalyzerStatement, SnowballLanguage, Tokenizer,
 };
@@ -70,7 +85,9 @@ pub use define_database::{define_database, DefineDatabaseStatement};
 pub use define_event::{define_event, DefineEventStatement};
 pub use define_field::{define_field, DefineFieldStatement};
 pub use define_function::{define_function, DefineFunctionStatement, FunctionArgument};
-pub use define_index::{define_index, search_analyzer, DefineIndexStatement, Scoring};
+pub use define_index::{
+    define_index, search_analyzer, DefineIndexStatement, Scoring, VectorDistance, VectorType,
+};
 pub use define_model::{define_model, DefineModelStatement, ModelName, ModelVersion};
 pub use define_namespace::{define_namespace, DefineNamespaceStatement};
 pub use define_param::{define_param, DefineParamStatement};
@@ -82,11 +99,21 @@ pub use delete::{delete, delete_only, DeleteStatement, DeleteStatementMini};
 pub use for_loop::{for_, ForLoopStatement};
 pub use for_permission::{for_permission, ForPermission, Permissions};
 pub use ifelse::{if_, IfElseStatement};
-pub use info::{info_for, InfoStatement};
+pub use info::{
+    info_for, DatabaseInfo, Definitions, InfoStatement, KvInfo, NamespaceInfo, ScopeInfo,
+    TableInfo,
+};
 pub use insert::{insert, InsertStatement, Insertables};
+pub use kill::{kill, KillStatement};
 pub use let_::{let_, LetStatement};
-pub use live_select::{live, LiveSelectStatement};
+pub use live_select::{live, LiveQuery, LiveSelectStatement, Notification};
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use rebuild_index::{rebuild_index, RebuildIndexStatement};
 pub use relate::{relate, relate_only, RelateStatement};
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use remove_access::{remove_access, RemoveAccessStatement};
 pub use remove_analyzer::{remove_analyzer, RemoveAnalyzerStatement};
 pub use remove_database::{remove_database, RemoveDatabaseStatement};
 pub use remove_event::{remove_event, RemoveEventStatement};
@@ -106,18 +133,30 @@ pub use select::{
     order, select, select_diff, select_value, Order, Orderables, SelectStatement,
     SelectStatementMini, Selectables, Splittables,
 };
-pub use show::{show_changes_for_table, ShowChangesStatement};
+pub use select_explain::{
+    assert_no_full_table_scan, assert_uses_index, PlanOperation, PlanStep, QueryPlan,
+};
+pub use select_pagination::{
+    Cursor, Page, Paginated, SelectStatementKeyset, SelectStatementPaginated,
+};
+pub use show::{show_changes_for_table, Change, ChangeSet, ShowChangesStatement};
 pub use sleep::{sleep, SleepStatement};
 pub use subquery::Subquery;
 pub use throw::{throw, ThrowStatement};
 pub use transaction::{
-    begin_transaction, transaction_deprecated, BeginTransactionStatement, TransactionCompletion,
+    begin_transaction, db_transaction, db_transaction_with_retry, transaction_deprecated,
+    BeginTransactionStatement, DbTransaction, StatementOutput, TransactionCompletion,
+    TransactionOutputs,
 };
 pub use update::{update, update_only, UpdateStatement};
+#[cfg(feature = "surrealdb-v2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+pub use upsert::{upsert, upsert_only, UpsertStatement};
 pub use use_::{use_, UseStatement};
 
 /// helpers for statements
 pub mod utils {
+    pub use super::utils_batch::{batch, BatchQueries, BatchQuery, Batchable, QueryBatch};
     pub use super::utils_block::{block, block_deprecated, Block};
     pub use super::utils_chain::{chain, Chainable, QueryChain};
 }
@@ -137,3 +176,30 @@ impl Display for NamespaceOrDatabase {
         write!(f, "{}", stringified)
     }
 }
+
+/// How a DEFINE statement behaves when the resource being defined already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefineMode {
+    /// `IF NOT EXISTS`. Only define the resource if it does not exist yet.
+    IfNotExists,
+    /// `OVERWRITE`. Replace the existing definition of the resource.
+    Overwrite,
+}
+
+impl Display for DefineMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stringified = match self {
+            DefineMode::IfNotExists => "IF NOT EXISTS",
+            DefineMode::Overwrite => "OVERWRITE",
+        };
+        write!(f, "{}", stringified)
+    }
+}
+
+/// Builds the head of a DEFINE statement e.g `DEFINE TABLE IF NOT EXISTS`
+pub(crate) fn define_prefix(resource: &str, mode: Option<DefineMode>) -> String {
+    match mode {
+        Some(mode) => format!("DEFINE {resource} {mode}"),
+        None => format!("DEFINE {resource}"),
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Statement syntax
+// REBUILD INDEX [ IF EXISTS ] @name ON [ TABLE ] @table;
+
+use std::fmt::{self, Display};
+
+use crate::{
+    BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement, Table, TableIndex,
+};
+
+/// Rebuild index statement. Recomputes an existing index from the current
+/// content of its table e.g after a bulk import.
+///
+/// # Arguments
+/// * `index` - The name of the index to be rebuilt. Can be a string or a TableIndex type.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::rebuild_index};
+/// # let user = Table::new("user");
+/// # let email_idx = TableIndex::new("email_idx");
+///
+/// let statement = rebuild_index(email_idx).on_table(user);
+/// assert_eq!(statement.build(), "REBUILD INDEX email_idx ON TABLE user;");
+/// ```
+pub fn rebuild_index(index: impl Into<TableIndex>) -> RebuildIndexStatement {
+    RebuildIndexStatement {
+        index: index.into(),
+        table: None,
+        if_exists: false,
+    }
+}
+
+/// Rebuild index statement
+pub struct RebuildIndexStatement {
+    index: TableIndex,
+    table: Option<Table>,
+    if_exists: bool,
+}
+
+impl RebuildIndexStatement {
+    /// Set the table of the index to rebuild
+    /// # Arguments
+    ///
+    /// * `table` - The name of the table the index is defined on. Can be a string or a Table type.
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::rebuild_index};
+    /// # let user = Table::new("user");
+    /// # let email_idx = TableIndex::new("email_idx");
+    ///  rebuild_index(email_idx).on_table(user);
+    /// ```
+    pub fn on_table(mut self, table: impl Into<Table>) -> Self {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Only rebuild the index if it exists rather than erroring.
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::rebuild_index};
+    ///
+    /// let statement = rebuild_index("email_idx").if_exists().on_table("user");
+    /// assert_eq!(statement.build(), "REBUILD INDEX IF EXISTS email_idx ON TABLE user;");
+    /// ```
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl Buildable for RebuildIndexStatement {
+    fn build(&self) -> String {
+        let mut query = "REBUILD INDEX".to_string();
+        if self.if_exists {
+            query = format!("{query} IF EXISTS");
+        }
+        query = format!("{query} {}", self.index);
+        if let Some(table) = &self.table {
+            query = format!("{} ON TABLE {}", query, table);
+        }
+        format!("{};", query)
+    }
+}
+
+impl Display for RebuildIndexStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl Parametric for RebuildIndexStatement {
+    fn get_bindings(&self) -> BindingsList {
+        vec![]
+    }
+}
+
+impl Erroneous for RebuildIndexStatement {}
+
+impl Queryable for RebuildIndexStatement {}
+impl SingleStatement for RebuildIndexStatement {}
+
+/// Extracts the index name and table from an index definition
+/// e.g `DEFINE INDEX IF NOT EXISTS email_idx ON TABLE user FIELDS email UNIQUE;`.
+pub(crate) fn index_name_and_table(definition: &str) -> Option<(String, String)> {
+    let rest = definition
+        .trim()
+        .strip_prefix("DEFINE INDEX ")?
+        .trim_start();
+    let rest = rest
+        .strip_prefix("IF NOT EXISTS ")
+        .or_else(|| rest.strip_prefix("OVERWRITE "))
+        .unwrap_or(rest);
+
+    let mut words = rest.split_whitespace();
+    let name = words.next()?.to_string();
+    let table = match (words.next(), words.next()) {
+        (Some("ON"), Some("TABLE")) => words.next(),
+        (Some("ON"), table) => table,
+        _ => None,
+    }?;
+
+    Some((name, table.trim_end_matches(';').to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::TableIndex;
+
+    #[test]
+    fn test_rebuild_index() {
+        let user = Table::new("user");
+        let email_idx = TableIndex::new("email_idx");
+
+        let statement = rebuild_index(email_idx).on_table(user);
+        assert_eq!(statement.build(), "REBUILD INDEX email_idx ON TABLE user;");
+    }
+
+    #[test]
+    fn test_rebuild_index_if_exists() {
+        let statement = rebuild_index("email_idx").if_exists().on_table("user");
+        assert_eq!(
+            statement.build(),
+            "REBUILD INDEX IF EXISTS email_idx ON TABLE user;"
+        );
+    }
+
+    #[test]
+    fn test_index_name_and_table() {
+        assert_eq!(
+            index_name_and_table("DEFINE INDEX email_idx ON TABLE user FIELDS email UNIQUE;"),
+            Some(("email_idx".to_string(), "user".to_string()))
+        );
+        assert_eq!(
+            index_name_and_table("DEFINE INDEX OVERWRITE email_idx ON user FIELDS email;"),
+            Some(("email_idx".to_string(), "user".to_string()))
+        );
+        assert_eq!(
+            index_name_and_table("DEFINE INDEX IF NOT EXISTS email_idx ON TABLE user;"),
+            Some(("email_idx".to_string(), "user".to_string()))
+        );
+        assert_eq!(index_name_and_table("DEFINE EVENT ev ON TABLE user;"), None);
+    }
+}
@@ -19,7 +19,9 @@ use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     derive_binding_and_errors_from_value,
-    traits::{BindingsList, Buildable, Edge, Erroneous, ErrorList, Parametric, Queryable},
+    traits::{
+        BindingsList, Buildable, Edge, Erroneous, ErrorList, Parametric, Queryable, SingleStatement,
+    },
     types::{DurationLike, ReturnType},
     ReturnableDefault, ReturnableStandard, Setter, ToRaw,
 };
@@ -258,6 +260,7 @@ where
 }
 
 impl<T> Queryable for RelateStatement<T> where T: Serialize + DeserializeOwned + Edge {}
+impl<T> SingleStatement for RelateStatement<T> where T: Serialize + DeserializeOwned + Edge {}
 
 impl<T> Erroneous for RelateStatement<T>
 where
@@ -0,0 +1,138 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+/*
+ *
+ *
+REMOVE statement
+
+Statement syntax
+REMOVE [
+    NAMESPACE @name
+    | DATABASE @name
+    | ACCESS @name ON [ NAMESPACE | DATABASE ]
+    | LOGIN @name ON [ NAMESPACE | DATABASE ]
+    | TOKEN @name ON [ NAMESPACE | DATABASE ]
+    | USER @name ON [ ROOT | NAMESPACE | DATABASE ]
+    | SCOPE @name
+    | TABLE @name
+    | EVENT @name ON [ TABLE ] @table
+    | FIELD @name ON [ TABLE ] @table
+    | INDEX @name ON [ TABLE ] @table
+]
+ * */
+
+use std::fmt::{self, Display};
+
+use crate::{Access, BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement};
+
+use super::NamespaceOrDatabase;
+
+/// Remove access statement
+///
+/// # Arguments
+///
+/// * `access` - The name of the access method to be removed. Can be a string or an Access type.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::remove_access};
+/// # let account = Access::new("account");
+/// let statement = remove_access(account).on_database();
+/// assert_eq!(statement.build(), "REMOVE ACCESS account ON DATABASE;");
+/// ```
+pub fn remove_access(access: impl Into<Access>) -> RemoveAccessStatementInit {
+    RemoveAccessStatementInit {
+        access: access.into(),
+        on: None,
+    }
+}
+
+/// Remove access statement
+pub struct RemoveAccessStatementInit {
+    access: Access,
+    on: Option<NamespaceOrDatabase>,
+}
+
+impl RemoveAccessStatementInit {
+    /// Remove access method on namespace
+    pub fn on_namespace(mut self) -> RemoveAccessStatement {
+        self.on = Some(NamespaceOrDatabase::Namespace);
+        self.into()
+    }
+
+    /// Remove access method on database
+    pub fn on_database(mut self) -> RemoveAccessStatement {
+        self.on = Some(NamespaceOrDatabase::Database);
+        self.into()
+    }
+}
+
+/// Remove access statement
+pub struct RemoveAccessStatement(RemoveAccessStatementInit);
+
+impl From<RemoveAccessStatementInit> for RemoveAccessStatement {
+    fn from(init: RemoveAccessStatementInit) -> Self {
+        Self(init)
+    }
+}
+
+impl std::ops::Deref for RemoveAccessStatement {
+    type Target = RemoveAccessStatementInit;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Buildable for RemoveAccessStatement {
+    fn build(&self) -> String {
+        let mut query = format!("REMOVE ACCESS {}", self.access);
+
+        if let Some(on) = &self.on {
+            query = format!("{} ON {}", query, on);
+        }
+
+        format!("{};", query)
+    }
+}
+
+impl Display for RemoveAccessStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl Parametric for RemoveAccessStatement {
+    fn get_bindings(&self) -> BindingsList {
+        vec![]
+    }
+}
+
+impl Erroneous for RemoveAccessStatement {}
+
+impl Queryable for RemoveAccessStatement {}
+impl SingleStatement for RemoveAccessStatement {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_access_on_namespace() {
+        let access = Access::new("account");
+        let statement = remove_access(access).on_namespace();
+        assert_eq!(statement.build(), "REMOVE ACCESS account ON NAMESPACE;");
+    }
+
+    #[test]
+    fn remove_access_on_database() {
+        let access = Access::new("account");
+        let statement = remove_access(access).on_database();
+        assert_eq!(statement.build(), "REMOVE ACCESS account ON DATABASE;");
+    }
+}
@@ -6,7 +6,7 @@
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     Binding,
 };
 
@@ -36,6 +36,7 @@ pub struct RemoveAnalyzerStatement {
 }
 
 impl Queryable for RemoveAnalyzerStatement {}
+impl SingleStatement for RemoveAnalyzerStatement {}
 
 impl Erroneous for RemoveAnalyzerStatement {
     fn get_errors(&self) -> crate::ErrorList {
@@ -26,7 +26,7 @@ REMOVE [
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::Database,
 };
 
@@ -95,6 +95,7 @@ impl Parametric for RemoveDatabaseStatement {
 impl Erroneous for RemoveDatabaseStatement {}
 
 impl Queryable for RemoveDatabaseStatement {}
+impl SingleStatement for RemoveDatabaseStatement {}
 
 #[cfg(test)]
 mod tests {
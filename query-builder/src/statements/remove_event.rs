@@ -24,7 +24,7 @@ REMOVE [
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::{Event, Table},
 };
 
@@ -102,6 +102,7 @@ impl Parametric for RemoveEventStatement {
 impl Erroneous for RemoveEventStatement {}
 
 impl Queryable for RemoveEventStatement {}
+impl SingleStatement for RemoveEventStatement {}
 
 #[cfg(test)]
 mod tests {
@@ -25,7 +25,9 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Field, Parametric, Queryable, Table};
+use crate::{
+    BindingsList, Buildable, Erroneous, Field, Parametric, Queryable, SingleStatement, Table,
+};
 
 /// Remove field statement
 ///
@@ -100,6 +102,7 @@ impl Parametric for RemoveFieldStatement {
 impl Erroneous for RemoveFieldStatement {}
 
 impl Queryable for RemoveFieldStatement {}
+impl SingleStatement for RemoveFieldStatement {}
 
 #[cfg(test)]
 mod tests {
@@ -7,7 +7,7 @@
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     Binding,
 };
 
@@ -58,6 +58,7 @@ pub struct RemoveFunctionStatement {
 }
 
 impl Queryable for RemoveFunctionStatement {}
+impl SingleStatement for RemoveFunctionStatement {}
 
 impl Erroneous for RemoveFunctionStatement {
     fn get_errors(&self) -> crate::ErrorList {
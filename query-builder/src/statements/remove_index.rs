@@ -25,7 +25,9 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Table, TableIndex};
+use crate::{
+    BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement, Table, TableIndex,
+};
 
 /// Remove index statement
 ///
@@ -99,6 +101,7 @@ impl Parametric for RemoveIndexStatement {
 impl Erroneous for RemoveIndexStatement {}
 
 impl Queryable for RemoveIndexStatement {}
+impl SingleStatement for RemoveIndexStatement {}
 
 #[cfg(test)]
 mod tests {
@@ -25,7 +25,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Login, Parametric, Queryable};
+use crate::{BindingsList, Buildable, Erroneous, Login, Parametric, Queryable, SingleStatement};
 
 use super::NamespaceOrDatabase;
 
@@ -131,6 +131,7 @@ impl Parametric for RemoveLoginStatement {
 impl Erroneous for RemoveLoginStatement {}
 
 impl Queryable for RemoveLoginStatement {}
+impl SingleStatement for RemoveLoginStatement {}
 
 #[cfg(test)]
 mod tests {
@@ -5,7 +5,7 @@
  */
 use std::fmt;
 
-use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable};
+use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement};
 
 use super::define_model::{ModelName, ModelVersion};
 
@@ -48,6 +48,7 @@ impl RemoveModelStatement {
 }
 
 impl Queryable for RemoveModelStatement {}
+impl SingleStatement for RemoveModelStatement {}
 
 impl Erroneous for RemoveModelStatement {
     fn get_errors(&self) -> crate::ErrorList {
@@ -26,7 +26,7 @@ REMOVE [
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::Namespace,
 };
 
@@ -76,6 +76,7 @@ impl Parametric for RemoveNamespaceStatement {
 impl Erroneous for RemoveNamespaceStatement {}
 
 impl Queryable for RemoveNamespaceStatement {}
+impl SingleStatement for RemoveNamespaceStatement {}
 
 #[cfg(test)]
 mod tests {
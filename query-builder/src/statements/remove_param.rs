@@ -6,7 +6,7 @@
 use std::fmt::{self};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     Param,
 };
 
@@ -36,6 +36,7 @@ pub struct RemoveParamStatement {
 }
 
 impl Queryable for RemoveParamStatement {}
+impl SingleStatement for RemoveParamStatement {}
 
 impl Erroneous for RemoveParamStatement {
     fn get_errors(&self) -> crate::ErrorList {
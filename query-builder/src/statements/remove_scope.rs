@@ -27,7 +27,7 @@ REMOVE [
 use std::fmt::{self, Display};
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::Scope,
 };
 
@@ -63,6 +63,7 @@ impl RemoveScopeStatement {
 }
 
 impl Queryable for RemoveScopeStatement {}
+impl SingleStatement for RemoveScopeStatement {}
 impl Erroneous for RemoveScopeStatement {}
 
 impl Parametric for RemoveScopeStatement {
@@ -23,7 +23,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Table};
+use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement, Table};
 
 /// Remove table statement
 ///
@@ -71,6 +71,7 @@ impl Parametric for RemoveTableStatement {
 impl Erroneous for RemoveTableStatement {}
 
 impl Queryable for RemoveTableStatement {}
+impl SingleStatement for RemoveTableStatement {}
 
 #[cfg(test)]
 mod tests {
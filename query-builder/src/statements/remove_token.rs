@@ -23,7 +23,10 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Scope, Token, TokenTarget};
+use crate::{
+    BindingsList, Buildable, Erroneous, Parametric, Queryable, Scope, SingleStatement, Token,
+    TokenTarget,
+};
 
 /// Remove token statement
 ///
@@ -123,6 +126,7 @@ impl Parametric for RemoveTokenStatement {
 impl Erroneous for RemoveTokenStatement {}
 
 impl Queryable for RemoveTokenStatement {}
+impl SingleStatement for RemoveTokenStatement {}
 
 #[cfg(test)]
 mod test {
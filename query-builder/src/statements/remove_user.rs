@@ -26,7 +26,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
-use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, User};
+use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement, User};
 
 /// Remove user statement
 ///
@@ -140,6 +140,7 @@ impl Parametric for RemoveUserStatement {
 impl Erroneous for RemoveUserStatement {}
 
 impl Queryable for RemoveUserStatement {}
+impl SingleStatement for RemoveUserStatement {}
 
 #[cfg(test)]
 mod test {
@@ -4,7 +4,10 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use crate::{BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable, ValueLike};
+use crate::{
+    BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable, SingleStatement,
+    ValueLike,
+};
 
 use super::select::Fetchables;
 
@@ -93,6 +96,7 @@ impl Erroneous for ReturnStatement {
 }
 
 impl Queryable for ReturnStatement {}
+impl SingleStatement for ReturnStatement {}
 
 #[cfg(test)]
 mod tests {
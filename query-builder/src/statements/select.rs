@@ -30,17 +30,25 @@ use std::{
     ops::Deref,
 };
 
+use futures::{stream, Stream, TryStreamExt};
 use serde::{de::DeserializeOwned, Serialize};
-use surrealdb::{engine::local::Db, sql, Surreal};
+use surrealdb::{engine::local::Db, sql, Connection, Surreal};
 
 use crate::{
     Aliasable, All, Binding, BindingsList, Buildable, Conditional, DurationLike, Erroneous,
     ErrorList, Field, Filter, Function, IndexName, Model, NumberLike, Parametric, Queryable,
-    ReturnableSelect, ReturnableStandard, SurrealId, SurrealOrmResult, SurrealSimpleId,
-    SurrealUlid, SurrealUuid, Table, ToRaw, ValueLike,
+    ReturnableSelect, ReturnableStandard, Runnable, SingleStatement, SurrealCrud, SurrealId,
+    SurrealOrmError, SurrealOrmResult, SurrealSimpleId, SurrealUlid, SurrealUuid, Table, ToRaw,
+    ValueLike,
 };
 
-use super::Subquery;
+use super::{
+    select_explain::{QueryPlan, RawPlanStep},
+    select_pagination::{
+        keyset_condition, Cursor, SelectStatementKeyset, SelectStatementPaginated,
+    },
+    Subquery,
+};
 
 /// Creates a new `Order` instance with the specified database field.
 ///
@@ -815,7 +823,7 @@ pub struct SelectStatementInit {
     projections: String,
     targets: Vec<String>,
     only: bool,
-    where_: Option<String>,
+    where_: Option<Filter>,
     split: Vec<String>,
     group_by: Vec<String>,
     group_all: bool,
@@ -839,6 +847,7 @@ impl Aliasable for SelectStatement {
 }
 
 impl Queryable for SelectStatement {}
+impl SingleStatement for SelectStatement {}
 
 impl ReturnableSelect for SelectStatement {}
 
@@ -1154,8 +1163,7 @@ impl SelectStatement {
     ///                 );
     pub fn where_(mut self, condition: impl Conditional + Clone) -> Self {
         self.update_bindings(condition.get_bindings());
-        let condition = Filter::new(condition);
-        self.0.where_ = Some(condition.build());
+        self.0.where_ = Some(Filter::new(condition));
         self
     }
 
@@ -1421,6 +1429,180 @@ impl SelectStatement {
         self
     }
 
+    /// Paginates the query with a cursor instead of an offset, which stays fast and
+    /// stable on large tables. The page is ordered by the `order_by` fields with the
+    /// record id as the tie-breaker, and continues strictly after the record the cursor
+    /// points at. Any `limit` or `start` set on the statement is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The `next_cursor` of the previous page or `None` for the first page.
+    /// * `page_size` - The number of records in a page.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::select};
+    /// # let user = Table::new("user");
+    /// # let age = Field::new("age");
+    /// let first_page = select(All)
+    ///     .from(user)
+    ///     .order_by(age.desc())
+    ///     .paginate_after(None, 20);
+    ///
+    /// assert_eq!(
+    ///     first_page.to_raw().build(),
+    ///     "SELECT * FROM user ORDER BY age DESC, id ASC LIMIT 21;"
+    /// );
+    /// ```
+    pub fn paginate_after(
+        mut self,
+        cursor: impl Into<Option<Cursor>>,
+        page_size: u64,
+    ) -> SelectStatementKeyset {
+        let id = Field::new("id");
+        if !self
+            .0
+            .order_by
+            .iter()
+            .any(|o| o.field.build() == id.build())
+        {
+            self.0.order_by.push(order(id).asc());
+        }
+
+        if page_size == 0 {
+            self.0
+                .errors
+                .push("Page size must be greater than 0".to_string());
+        }
+
+        if self
+            .0
+            .order_by
+            .iter()
+            .any(|o| matches!(o.option, Some(OrderOption::Rand)))
+        {
+            self.0
+                .errors
+                .push("Cursor pagination cannot be used with random ordering".to_string());
+        }
+
+        let keys = self
+            .0
+            .order_by
+            .iter()
+            .map(|o| {
+                (
+                    o.field.clone(),
+                    matches!(o.direction, Some(OrderDirection::Desc)),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(cursor) = cursor.into() {
+            match keyset_condition(&keys, &cursor) {
+                Ok((condition, bindings)) => {
+                    let condition = Filter::from(condition).with_bindings(bindings.clone());
+                    self.0.where_ = Some(match self.0.where_.take() {
+                        Some(existing) => Filter::from(format!("({existing}) AND ({condition})"))
+                            .with_bindings(existing.get_bindings())
+                            .with_bindings(condition.get_bindings()),
+                        None => condition,
+                    });
+                    self.update_bindings(bindings);
+                }
+                Err(error) => self.0.errors.push(error),
+            }
+        }
+
+        self.0.start = None;
+        // One more record is fetched to know whether there is a next page. Numbers are
+        // signed in SurrealQL, so the limit is capped to the largest one.
+        let limit = page_size.saturating_add(1).min(i64::MAX as u64);
+        let statement = self.limit(limit);
+        SelectStatementKeyset::new(
+            statement,
+            keys.into_iter().map(|(field, _)| field).collect(),
+            page_size,
+        )
+    }
+
+    /// Runs the query in chunks and returns the records as a stream, so that large
+    /// result sets never have to be held in memory at once. Each chunk is fetched with
+    /// `paginate_after`, i.e. by ranges of the `order_by` fields and the record id rather
+    /// than by offset, so every chunk stays as fast as the first one. The ordered fields
+    /// and the id must therefore be part of the projections.
+    ///
+    /// # Arguments
+    ///
+    /// * `db` - The database connection.
+    /// * `chunk_size` - The number of records fetched from the database at a time.
+    ///     Must be greater than 0, otherwise the stream yields a single error.
+    ///
+    /// The chunks replace any `limit` or `start` of the statement, so the stream yields a single
+    /// error if either is set. Use `take` and `skip` on the stream instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let mut users = select(All)
+    ///     .from(User::table())
+    ///     .where_(age.gt(18))
+    ///     .return_stream::<User>(db.clone(), 1000);
+    ///
+    /// while let Some(user) = users.try_next().await? {
+    ///     export(user);
+    /// }
+    /// ```
+    pub fn return_stream<T>(
+        &self,
+        db: Surreal<impl Connection>,
+        chunk_size: u64,
+    ) -> impl Stream<Item = SurrealOrmResult<T>> + Unpin
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut statement = self.clone();
+        if statement.0.limit.is_some() || statement.0.start.is_some() {
+            statement.0.errors.push(
+                "Cannot stream a query with a limit or start. Use take or skip on the stream instead"
+                    .to_string(),
+            );
+        }
+        let chunks = stream::try_unfold(Some(None), move |cursor: Option<Option<Cursor>>| {
+            let statement = statement.clone();
+            let db = db.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Ok(None);
+                };
+                let page = statement
+                    .paginate_after(cursor.clone(), chunk_size)
+                    .return_page::<T>(db)
+                    .await?;
+
+                let next_cursor = match page.next_cursor {
+                    Some(next_cursor) if Some(&next_cursor) == cursor.as_ref() => {
+                        return Err(SurrealOrmError::QueryBuilder(
+                            "Cannot stream the query. The ordered fields and the record id must be \
+                            part of the projections."
+                                .to_string(),
+                        ))
+                    }
+                    next_cursor => next_cursor.map(Some),
+                };
+                let records = stream::iter(page.items.into_iter().map(Ok::<T, SurrealOrmError>));
+
+                Ok(Some((records, next_cursor)))
+            }
+        });
+
+        Box::pin(chunks.try_flatten())
+    }
+
     /// Adds a field or many fields to the list of fields to fetch in the current query.
     /// You can add as list in a single `fetch` call or chain to accumulate fields to fetch.
     ///
@@ -1529,6 +1711,30 @@ impl SelectStatement {
         self
     }
 
+    /// Runs the query with `EXPLAIN FULL` and returns the typed plan, i.e which tables
+    /// are iterated, which indexes are used and how many records are fetched.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// use surreal_orm::statements::assert_uses_index;
+    ///
+    /// let plan = select(All)
+    ///     .from(User::table())
+    ///     .where_(email.eq("oyelowo@example.com"))
+    ///     .explain_plan(db.clone())
+    ///     .await?;
+    ///
+    /// assert_uses_index(&plan, "email_idx");
+    /// ```
+    pub async fn explain_plan(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<QueryPlan> {
+        let mut response = self.clone().explain_full().run_checked(db).await?;
+        let steps = response
+            .take::<Vec<RawPlanStep>>(0)
+            .map_err(SurrealOrmError::Deserialization)?;
+
+        Ok(steps.into())
+    }
+
     fn update_bindings(&mut self, bindings: BindingsList) -> &mut Self {
         // let mut updated_params = vec![];
         // updated_params.extend(self.________params_accumulator.to_vec());
@@ -1677,6 +1883,93 @@ impl<T: Model> SelectStatementMini<T> {
         self.0 .0.parallel = true;
         self
     }
+
+    /// Paginates the query by page number and also fetches the total number of
+    /// records matching the query in the same request. The total is counted by
+    /// `count_where` with the same WHERE clause and bindings as the page, so they cannot
+    /// drift apart. Any `limit` or `start` set on the statement is replaced.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page number, starting from 1.
+    /// * `page_size` - The number of records in a page.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let second_page = User::find_where(age.gt(18))
+    ///     .order_by(age.desc())
+    ///     .page(2, 20);
+    ///
+    /// assert_eq!(
+    ///     second_page.to_raw().build(),
+    ///     "SELECT * FROM user WHERE age > 18 ORDER BY age DESC LIMIT 20 START AT 20;\n\n\
+    ///     SELECT VALUE count FROM (SELECT count(age > 18) FROM user GROUP ALL);"
+    /// );
+    /// ```
+    pub fn page(mut self, page: u64, page_size: u64) -> SelectStatementPaginated
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let total = match self.0 .0.where_.clone() {
+            Some(condition) => <T as SurrealCrud>::count_where(condition),
+            None => <T as SurrealCrud>::count_all(),
+        };
+
+        if page == 0 {
+            self.0
+                 .0
+                .errors
+                .push("Page numbers start from 1".to_string());
+        }
+        if page_size == 0 {
+            self.0
+                 .0
+                .errors
+                .push("Page size must be greater than 0".to_string());
+        }
+        let start = page
+            .saturating_sub(1)
+            .checked_mul(page_size)
+            .unwrap_or_else(|| {
+                self.0
+                     .0
+                    .errors
+                    .push(format!("Page {page} of size {page_size} is out of range"));
+                0
+            });
+
+        let statement = self.0.limit(page_size).start(start);
+        SelectStatementPaginated::new(statement, total, page, page_size)
+    }
+
+    /// Runs the query with `EXPLAIN FULL` and returns the typed plan.
+    /// See `SelectStatement::explain_plan`.
+    pub async fn explain_plan(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<QueryPlan> {
+        self.0.explain_plan(db).await
+    }
+
+    /// Runs the query in chunks and returns the records as a stream.
+    /// See `SelectStatement::return_stream`.
+    pub fn return_stream(
+        &self,
+        db: Surreal<impl Connection>,
+        chunk_size: u64,
+    ) -> impl Stream<Item = SurrealOrmResult<T>> + Unpin
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        self.0.return_stream::<T>(db, chunk_size)
+    }
+
+    /// Paginates the results with a cursor. See `SelectStatement::paginate_after`.
+    pub fn paginate_after(
+        self,
+        cursor: impl Into<Option<Cursor>>,
+        page_size: u64,
+    ) -> SelectStatementKeyset {
+        self.0.paginate_after(cursor, page_size)
+    }
 }
 
 impl<T> From<SelectStatement> for SelectStatementMini<T>
@@ -1708,6 +2001,7 @@ where
 }
 
 impl<T> Queryable for SelectStatementMini<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> SingleStatement for SelectStatementMini<T> where T: Serialize + DeserializeOwned + Model {}
 
 impl<T> ReturnableStandard<T> for SelectStatementMini<T>
 where
@@ -1751,6 +2045,7 @@ impl Buildable for SelectStatementCount {
 }
 
 impl Queryable for SelectStatementCount {}
+impl SingleStatement for SelectStatementCount {}
 
 impl ReturnableSelect for SelectStatementCount {}
 
@@ -0,0 +1,282 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Typed output of SELECT ... EXPLAIN [FULL].
+//
+// The database returns the plan as a list of steps e.g:
+// [
+//   { detail: { plan: { index: 'email_idx', operator: '=', value: 'a@b.c' }, table: 'user' }, operation: 'Iterate Index' },
+//   { detail: { type: 'Memory' }, operation: 'Collector' },
+//   { detail: { count: 1 }, operation: 'Fetch' }
+// ]
+
+use std::fmt::{self, Display};
+
+use serde::Deserialize;
+
+/// The operation performed by a step of a query plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanOperation {
+    /// Scans every record of a table.
+    IterateTable,
+    /// Looks up records through an index.
+    IterateIndex,
+    /// Fetches a single record by its id.
+    IterateThing,
+    /// Iterates over a value e.g a param or an array of record ids.
+    IterateValue,
+    /// Collects the iterated records.
+    Collector,
+    /// Fetches the records. Only returned by `EXPLAIN FULL`.
+    Fetch,
+    /// Any other operation, with the name returned by the database.
+    Other(String),
+}
+
+impl From<String> for PlanOperation {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "Iterate Table" => Self::IterateTable,
+            "Iterate Index" => Self::IterateIndex,
+            "Iterate Thing" => Self::IterateThing,
+            "Iterate Value" => Self::IterateValue,
+            "Collector" => Self::Collector,
+            "Fetch" => Self::Fetch,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl Display for PlanOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operation = match self {
+            Self::IterateTable => "Iterate Table",
+            Self::IterateIndex => "Iterate Index",
+            Self::IterateThing => "Iterate Thing",
+            Self::IterateValue => "Iterate Value",
+            Self::Collector => "Collector",
+            Self::Fetch => "Fetch",
+            Self::Other(operation) => operation,
+        };
+        write!(f, "{operation}")
+    }
+}
+
+/// A step of a query plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlanStep {
+    /// The operation performed.
+    pub operation: PlanOperation,
+    /// The table iterated, if any.
+    pub table: Option<String>,
+    /// The index used to look up the records, if any.
+    pub index: Option<String>,
+    /// The operator matched against the index e.g `=`, if any.
+    pub operator: Option<String>,
+    /// The number of records fetched. Only set for the `Fetch` step.
+    pub count: Option<u64>,
+}
+
+/// The plan of a select statement returned by `explain_plan`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct QueryPlan {
+    /// The steps of the plan in order of execution.
+    pub steps: Vec<PlanStep>,
+}
+
+impl QueryPlan {
+    /// Returns the names of the indexes used by the query.
+    pub fn indexes_used(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .filter_map(|step| step.index.as_deref())
+            .collect()
+    }
+
+    /// Checks if the query uses the given index.
+    pub fn uses_index(&self, index: impl AsRef<str>) -> bool {
+        self.indexes_used().contains(&index.as_ref())
+    }
+
+    /// Returns the tables the query scans in full i.e without an index.
+    pub fn full_table_scans(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .filter(|step| step.operation == PlanOperation::IterateTable)
+            .filter_map(|step| step.table.as_deref())
+            .collect()
+    }
+
+    /// Checks if the query scans any table in full.
+    pub fn has_full_table_scan(&self) -> bool {
+        self.steps
+            .iter()
+            .any(|step| step.operation == PlanOperation::IterateTable)
+    }
+
+    /// Returns the number of records fetched. Only available with `EXPLAIN FULL`.
+    pub fn fetch_count(&self) -> Option<u64> {
+        self.steps.iter().find_map(|step| step.count)
+    }
+}
+
+impl Display for QueryPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let steps = self
+            .steps
+            .iter()
+            .map(|step| {
+                let mut detail = vec![];
+                if let Some(table) = &step.table {
+                    detail.push(format!("table: {table}"));
+                }
+                if let Some(index) = &step.index {
+                    detail.push(format!("index: {index}"));
+                }
+                if let Some(operator) = &step.operator {
+                    detail.push(format!("operator: {operator}"));
+                }
+                if let Some(count) = &step.count {
+                    detail.push(format!("count: {count}"));
+                }
+                if detail.is_empty() {
+                    step.operation.to_string()
+                } else {
+                    format!("{} ({})", step.operation, detail.join(", "))
+                }
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", steps.join(" -> "))
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawPlanStep {
+    operation: String,
+    #[serde(default)]
+    detail: RawPlanDetail,
+}
+
+#[derive(Deserialize, Default)]
+struct RawPlanDetail {
+    table: Option<String>,
+    plan: Option<RawIndexPlan>,
+    count: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct RawIndexPlan {
+    index: Option<String>,
+    operator: Option<String>,
+}
+
+impl From<Vec<RawPlanStep>> for QueryPlan {
+    fn from(steps: Vec<RawPlanStep>) -> Self {
+        let steps = steps
+            .into_iter()
+            .map(|RawPlanStep { operation, detail }| {
+                let (index, operator) = detail
+                    .plan
+                    .map(|plan| (plan.index, plan.operator))
+                    .unwrap_or_default();
+                PlanStep {
+                    operation: operation.into(),
+                    table: detail.table,
+                    index,
+                    operator,
+                    count: detail.count,
+                }
+            })
+            .collect();
+        Self { steps }
+    }
+}
+
+/// Asserts that the query plan uses the given index.
+/// Useful in tests to catch queries that silently fall back to full table scans.
+///
+/// # Example
+/// ```rust, ignore
+/// let plan = select(All)
+///     .from(User::table())
+///     .where_(email.eq("oyelowo@example.com"))
+///     .explain_plan(db.clone())
+///     .await?;
+///
+/// assert_uses_index(&plan, "email_idx");
+/// ```
+#[track_caller]
+pub fn assert_uses_index(plan: &QueryPlan, index: impl AsRef<str>) {
+    let index = index.as_ref();
+    assert!(
+        plan.uses_index(index),
+        "Expected the query to use index `{index}` but it used {:?}. Plan: {plan}",
+        plan.indexes_used()
+    );
+}
+
+/// Asserts that the query plan does not scan any table in full.
+#[track_caller]
+pub fn assert_no_full_table_scan(plan: &QueryPlan) {
+    assert!(
+        !plan.has_full_table_scan(),
+        "Expected the query not to scan any table in full but it scanned {:?}. Plan: {plan}",
+        plan.full_table_scans()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plan(steps: serde_json::Value) -> QueryPlan {
+        serde_json::from_value::<Vec<RawPlanStep>>(steps)
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn test_query_plan_with_index() {
+        let plan = plan(serde_json::json!([
+            {
+                "detail": {
+                    "plan": { "index": "email_idx", "operator": "=", "value": "a@b.c" },
+                    "table": "user"
+                },
+                "operation": "Iterate Index"
+            },
+            { "detail": { "type": "Memory" }, "operation": "Collector" },
+            { "detail": { "count": 1 }, "operation": "Fetch" }
+        ]));
+
+        assert_eq!(plan.steps[0].operation, PlanOperation::IterateIndex);
+        assert_eq!(plan.steps[0].table.as_deref(), Some("user"));
+        assert_eq!(plan.steps[0].operator.as_deref(), Some("="));
+        assert_eq!(plan.indexes_used(), vec!["email_idx"]);
+        assert_eq!(plan.fetch_count(), Some(1));
+        assert!(!plan.has_full_table_scan());
+        assert_eq!(
+            plan.to_string(),
+            "Iterate Index (table: user, index: email_idx, operator: =) -> Collector -> Fetch (count: 1)"
+        );
+
+        assert_uses_index(&plan, "email_idx");
+        assert_no_full_table_scan(&plan);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected the query to use index `email_idx`")]
+    fn test_assert_uses_index_fails_on_full_table_scan() {
+        let plan = plan(serde_json::json!([
+            { "detail": { "table": "user" }, "operation": "Iterate Table" },
+            { "detail": { "type": "Memory" }, "operation": "Collector" }
+        ]));
+
+        assert_eq!(plan.full_table_scans(), vec!["user"]);
+        assert_eq!(plan.fetch_count(), None);
+        assert_uses_index(&plan, "email_idx");
+    }
+}
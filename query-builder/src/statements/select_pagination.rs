@@ -0,0 +1,568 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Keyset (cursor) and offset pagination over a select statement.
+//
+// The page is fetched with the ORDER BY fields of the statement plus the record id as the
+// tie-breaker. The cursor holds the values of these fields for the last record of the page
+// and the next page continues strictly after it e.g for `ORDER BY age DESC, id ASC`:
+// WHERE (age < $age) OR (age = $age AND id > $id) ORDER BY age DESC, id ASC LIMIT page_size + 1
+//
+// Offset pagination fetches a page with LIMIT/START and counts all the matching records with
+// the same condition in the same request.
+
+use std::fmt::{self, Display};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use surrealdb::{sql, Connection, Surreal};
+
+use crate::{
+    Binding, BindingsList, Buildable, Erroneous, ErrorList, Field, Parametric, Queryable, Runnable,
+    SingleStatement, SurrealOrmError, SurrealOrmResult,
+};
+
+use super::{
+    select::SelectStatementCount,
+    utils::{chain, QueryChain},
+    SelectStatement,
+};
+
+/// An opaque cursor pointing at the last record of a page.
+/// Pass it to `paginate_after` to fetch the next page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Returns the cursor as a string e.g for returning it to an API client.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn encode(values: Vec<sql::Value>) -> Self {
+        let values = sql::Value::from(values).to_string();
+        Self(values.bytes().map(|b| format!("{b:02x}")).collect())
+    }
+
+    fn decode(&self) -> Option<Vec<sql::Value>> {
+        let bytes = (0..self.0.len())
+            .step_by(2)
+            .map(|i| {
+                self.0
+                    .get(i..i + 2)
+                    .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+            })
+            .collect::<Option<Vec<u8>>>()?;
+        let values = String::from_utf8(bytes).ok()?;
+
+        match sql::value(&values).ok()? {
+            sql::Value::Array(values) if values.iter().all(is_literal) => Some(values.0),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the value is plain data. The cursor comes back from the client, so values which
+/// would be evaluated by the database e.g subqueries, functions or params are rejected.
+fn is_literal(value: &sql::Value) -> bool {
+    match value {
+        sql::Value::None
+        | sql::Value::Null
+        | sql::Value::Bool(_)
+        | sql::Value::Number(_)
+        | sql::Value::Strand(_)
+        | sql::Value::Duration(_)
+        | sql::Value::Datetime(_)
+        | sql::Value::Uuid(_)
+        | sql::Value::Geometry(_)
+        | sql::Value::Bytes(_) => true,
+        sql::Value::Array(values) => values.iter().all(is_literal),
+        sql::Value::Object(object) => object.values().all(is_literal),
+        sql::Value::Thing(thing) => match &thing.id {
+            sql::Id::Number(_) | sql::Id::String(_) => true,
+            sql::Id::Array(values) => values.iter().all(is_literal),
+            sql::Id::Object(object) => object.values().all(is_literal),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+impl Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<String> for Cursor {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Cursor {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+/// A page of records returned by keyset pagination.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// The records of the page.
+    pub items: Vec<T>,
+    /// Cursor of the last record of the page. `None` when there are no more records.
+    pub next_cursor: Option<Cursor>,
+    /// Whether there are more records after this page.
+    pub has_more: bool,
+}
+
+/// Builds the keyset condition for continuing after the cursor.
+/// `keys` are the ordering fields with whether they are sorted descendingly.
+pub(crate) fn keyset_condition(
+    keys: &[(Field, bool)],
+    cursor: &Cursor,
+) -> Result<(String, BindingsList), String> {
+    let values = cursor
+        .decode()
+        .filter(|values| values.len() == keys.len())
+        .ok_or_else(|| {
+            "Invalid pagination cursor. Make sure it was returned by a page of the same query."
+                .to_string()
+        })?;
+
+    let bindings = values.into_iter().map(Binding::new).collect::<Vec<_>>();
+    let params = bindings
+        .iter()
+        .map(|binding| binding.get_param_dollarised())
+        .collect::<Vec<_>>();
+
+    let condition = keys
+        .iter()
+        .enumerate()
+        .map(|(index, (field, descending))| {
+            let mut parts = keys[..index]
+                .iter()
+                .zip(&params)
+                .map(|((previous, _), param)| format!("{} = {param}", previous.build()))
+                .collect::<Vec<_>>();
+            let operator = if *descending { "<" } else { ">" };
+            parts.push(format!("{} {operator} {}", field.build(), params[index]));
+            format!("({})", parts.join(" AND "))
+        })
+        .collect::<Vec<_>>()
+        .join(" OR ");
+
+    Ok((condition, bindings))
+}
+
+/// Picks the value at a field path e.g `name.first` from a record, if the record has it.
+fn pick(record: &sql::Value, path: &str) -> Option<sql::Value> {
+    path.split('.')
+        .try_fold(record.clone(), |value, key| match value {
+            sql::Value::Object(object) => object.get(key).cloned(),
+            _ => None,
+        })
+}
+
+/// A select statement paginated with a cursor. Created with `paginate_after`.
+#[derive(Debug, Clone)]
+pub struct SelectStatementKeyset {
+    statement: SelectStatement,
+    keys: Vec<Field>,
+    page_size: u64,
+}
+
+impl SelectStatementKeyset {
+    pub(crate) fn new(statement: SelectStatement, keys: Vec<Field>, page_size: u64) -> Self {
+        Self {
+            statement,
+            keys,
+            page_size,
+        }
+    }
+
+    /// Runs the statement against the database and returns the page with the cursor of the next page.
+    /// Fails if the ordered fields or the record id are not part of the projections.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let page = User::find_where(age.gt(18))
+    ///     .order_by(age.desc())
+    ///     .paginate_after(None, 20)
+    ///     .return_page::<User>(db.clone())
+    ///     .await?;
+    ///
+    /// if let Some(cursor) = page.next_cursor {
+    ///     let next_page = User::find_where(age.gt(18))
+    ///         .order_by(age.desc())
+    ///         .paginate_after(cursor, 20)
+    ///         .return_page::<User>(db.clone())
+    ///         .await?;
+    /// }
+    /// ```
+    pub async fn return_page<T>(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Page<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut response = self.run_checked(db).await?;
+        let mut records = match response
+            .take::<sql::Value>(0)
+            .map_err(SurrealOrmError::Deserialization)?
+        {
+            sql::Value::Array(records) => records.0,
+            sql::Value::None | sql::Value::Null => vec![],
+            record => vec![record],
+        };
+
+        let has_more = records.len() as u64 > self.page_size;
+        records.truncate(self.page_size as usize);
+
+        let next_cursor = match records.last() {
+            Some(last) if has_more => Some(Cursor::encode(
+                self.keys
+                    .iter()
+                    .map(|key| {
+                        pick(last, &key.build()).ok_or_else(|| {
+                            SurrealOrmError::QueryBuilder(format!(
+                                "Cannot paginate the query. The ordered field `{key}` is not part \
+                                of the projections, so the next page cannot be found."
+                            ))
+                        })
+                    })
+                    .collect::<SurrealOrmResult<Vec<_>>>()?,
+            )),
+            _ => None,
+        };
+
+        let items = records
+            .into_iter()
+            .map(|record| {
+                sql::from_value::<T>(record).map_err(|e| SurrealOrmError::Deserialization(e.into()))
+            })
+            .collect::<SurrealOrmResult<Vec<T>>>()?;
+
+        Ok(Page {
+            items,
+            next_cursor,
+            has_more,
+        })
+    }
+}
+
+impl Buildable for SelectStatementKeyset {
+    fn build(&self) -> String {
+        self.statement.build()
+    }
+}
+
+impl Parametric for SelectStatementKeyset {
+    fn get_bindings(&self) -> BindingsList {
+        self.statement.get_bindings()
+    }
+}
+
+impl Erroneous for SelectStatementKeyset {
+    fn get_errors(&self) -> ErrorList {
+        self.statement.get_errors()
+    }
+}
+
+impl Queryable for SelectStatementKeyset {}
+impl SingleStatement for SelectStatementKeyset {}
+
+impl Display for SelectStatementKeyset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+/// A page of records returned by offset pagination along with the total count.
+#[derive(Debug, Clone)]
+pub struct Paginated<T> {
+    /// The records of the page.
+    pub items: Vec<T>,
+    /// The total number of records matching the query.
+    pub total: u64,
+    /// The page number, starting from 1.
+    pub page: u64,
+    /// The total number of pages.
+    pub pages: u64,
+}
+
+/// A select statement paginated by page number together with the query counting all the
+/// matching records. Both run in a single request. Created with `page`.
+#[derive(Debug, Clone)]
+pub struct SelectStatementPaginated {
+    query: QueryChain,
+    page: u64,
+    page_size: u64,
+}
+
+impl SelectStatementPaginated {
+    pub(crate) fn new(
+        statement: SelectStatement,
+        total: SelectStatementCount,
+        page: u64,
+        page_size: u64,
+    ) -> Self {
+        Self {
+            query: chain(statement).chain(total),
+            page,
+            page_size,
+        }
+    }
+
+    /// Runs the page and the count against the database in one request.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let Paginated { items, total, page, pages } = User::find_where(age.gt(18))
+    ///     .order_by(age.desc())
+    ///     .page(2, 20)
+    ///     .return_page::<User>(db.clone())
+    ///     .await?;
+    /// ```
+    pub async fn return_page<T>(
+        &self,
+        db: Surreal<impl Connection>,
+    ) -> SurrealOrmResult<Paginated<T>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut response = self.run_checked(db).await?;
+        let items = response
+            .take::<Vec<T>>(0)
+            .map_err(SurrealOrmError::Deserialization)?;
+        let total = response
+            .take::<Option<u64>>(1)
+            .map_err(SurrealOrmError::Deserialization)?
+            .unwrap_or_default();
+
+        Ok(Paginated {
+            items,
+            total,
+            page: self.page,
+            pages: total.div_ceil(self.page_size.max(1)),
+        })
+    }
+}
+
+impl Buildable for SelectStatementPaginated {
+    fn build(&self) -> String {
+        self.query.build()
+    }
+}
+
+impl Parametric for SelectStatementPaginated {
+    fn get_bindings(&self) -> BindingsList {
+        self.query.get_bindings()
+    }
+}
+
+impl Erroneous for SelectStatementPaginated {
+    fn get_errors(&self) -> ErrorList {
+        self.query.get_errors()
+    }
+}
+
+impl Queryable for SelectStatementPaginated {}
+
+impl Display for SelectStatementPaginated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        statements::{select, SelectStatementMini},
+        *,
+    };
+
+    #[test]
+    fn test_cursor_round_trips_values() {
+        let values = vec![
+            sql::Value::from(32),
+            sql::Value::from("Oyelowo"),
+            sql::Value::from(sql::Thing::from(("user", "oyelowo"))),
+        ];
+        let cursor = Cursor::encode(values.clone());
+
+        assert!(!cursor.as_str().contains("Oyelowo"));
+        assert_eq!(cursor.decode(), Some(values));
+    }
+
+    #[test]
+    fn test_invalid_cursor_is_rejected() {
+        let keys = vec![(Field::new("id"), false)];
+        assert!(keyset_condition(&keys, &Cursor::from("not a cursor")).is_err());
+    }
+
+    #[test]
+    fn test_tampered_cursor_is_rejected() {
+        let tampered = |values: &str| {
+            Cursor::from(
+                values
+                    .bytes()
+                    .map(|b| format!("{b:02x}"))
+                    .collect::<String>(),
+            )
+        };
+        let keys = vec![(Field::new("age"), true), (Field::new("id"), false)];
+
+        assert!(keyset_condition(&keys, &tampered("[30, user:oyelowo]")).is_ok());
+        for values in [
+            "[(SELECT * FROM secret), user:oyelowo]",
+            "[30, (DELETE user)]",
+            "[crypto::argon2::generate('pass'), user:oyelowo]",
+            "[$auth.id, user:oyelowo]",
+            "[30, user:rand()]",
+            "[30, user:[(SELECT * FROM secret)]]",
+            "[{ age: (SELECT * FROM secret) }, user:oyelowo]",
+            "[1 + 1, user:oyelowo]",
+            "[secret.password, user:oyelowo]",
+        ] {
+            assert!(
+                keyset_condition(&keys, &tampered(values)).is_err(),
+                "{values} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_paginate_after_first_page() {
+        let age = Field::new("age");
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(age.gt(18))
+            .order_by(age.desc())
+            .paginate_after(None, 20);
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "SELECT * FROM user WHERE age > 18 ORDER BY age DESC, id ASC LIMIT 21;"
+        );
+    }
+
+    #[test]
+    fn test_paginate_after_zero_page_size_collects_error() {
+        let statement = select(All).from(Table::new("user")).paginate_after(None, 0);
+        assert_eq!(
+            statement.get_errors(),
+            vec!["Page size must be greater than 0".to_string()]
+        );
+
+        let statement = TestUser::find_where(Field::new("age").gt(18)).paginate_after(None, 0);
+        assert_eq!(statement.get_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_paginate_after_max_page_size_does_not_overflow() {
+        let statement = select(All)
+            .from(Table::new("user"))
+            .paginate_after(None, u64::MAX);
+
+        assert!(statement.get_errors().is_empty());
+        assert_eq!(
+            statement.to_raw().build(),
+            format!("SELECT * FROM user ORDER BY id ASC LIMIT {};", i64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_paginate_after_cursor() {
+        let age = Field::new("age");
+        let cursor = Cursor::encode(vec![
+            sql::Value::from(30),
+            sql::Value::from(sql::Thing::from(("user", "oyelowo"))),
+        ]);
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(age.gt(18))
+            .order_by(age.desc())
+            .paginate_after(cursor, 20);
+
+        assert!(statement.get_errors().is_empty());
+        assert_eq!(
+            statement.to_raw().build(),
+            "SELECT * FROM user WHERE (age > 18) AND ((age < 30) OR (age = 30 AND id > user:oyelowo)) \
+            ORDER BY age DESC, id ASC LIMIT 21;"
+        );
+    }
+
+    #[test]
+    fn test_paginate_after_invalid_cursor_collects_error() {
+        let statement = select(All)
+            .from(Table::new("user"))
+            .paginate_after(Cursor::from("abc"), 20);
+
+        assert_eq!(statement.get_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_page_counts_with_same_condition_and_bindings() {
+        let age = Field::new("age");
+        let statement = TestUser::find_where(age.gt(18))
+            .order_by(age.desc())
+            .page(3, 10);
+
+        assert!(statement.get_errors().is_empty());
+        assert_eq!(
+            statement.fine_tune_params(),
+            "SELECT * FROM user WHERE age > $_param_00000001 ORDER BY age DESC \
+            LIMIT $_param_00000002 START AT $_param_00000003;\n\n\
+            SELECT VALUE count FROM (SELECT count(age > $_param_00000004) FROM user GROUP ALL);"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "SELECT * FROM user WHERE age > 18 ORDER BY age DESC LIMIT 10 START AT 20;\n\n\
+            SELECT VALUE count FROM (SELECT count(age > 18) FROM user GROUP ALL);"
+        );
+
+        let where_param = |query: &str| {
+            let (_, rest) = query.split_once("age > ").unwrap();
+            rest.split(|c: char| c.is_whitespace() || c == ')')
+                .next()
+                .unwrap()
+                .to_string()
+        };
+        let (page_query, count_query) = statement
+            .build()
+            .split_once("\n\n")
+            .map(|(page_query, count_query)| (page_query.to_string(), count_query.to_string()))
+            .unwrap();
+        assert_eq!(where_param(&page_query), where_param(&count_query));
+    }
+
+    #[test]
+    fn test_page_without_condition_counts_all() {
+        let statement =
+            SelectStatementMini::<TestUser>::from(select(All).from(TestUser::table())).page(1, 10);
+        assert_eq!(
+            statement.to_raw().build(),
+            "SELECT * FROM user LIMIT 10 START AT 0;\n\n\
+            SELECT VALUE count FROM (SELECT count() FROM user GROUP ALL);"
+        );
+    }
+
+    #[test]
+    fn test_page_zero_collects_error() {
+        let statement = TestUser::find_where(Field::new("age").gt(18)).page(0, 10);
+        assert_eq!(statement.get_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_page_out_of_range_collects_error() {
+        let statement = TestUser::find_where(Field::new("age").gt(18)).page(u64::MAX, 10);
+        assert_eq!(
+            statement.get_errors(),
+            vec![format!("Page {} of size 10 is out of range", u64::MAX)]
+        );
+    }
+}
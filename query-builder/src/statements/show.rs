@@ -6,9 +6,12 @@
 
 use std::fmt;
 
+use serde::{de::DeserializeOwned, Deserialize};
+use surrealdb::{sql, Connection, Surreal};
+
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
-    DatetimeLike, NumberLike, TableLike,
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
+    DatetimeLike, NumberLike, Runnable, SurrealOrmError, SurrealOrmResult, TableLike, ToRaw,
 };
 
 /// Creates a SHOW CHANGES statement builder.
@@ -20,6 +23,9 @@ use crate::{
 ///
 /// let timestamp = chrono::DateTime::from_timestamp(4343434434, 232).unwrap();
 /// let statement = show_changes_for_table("reading").since(timestamp).limit(10);
+///
+/// let statement = show_changes_for_table("reading").since_versionstamp(0);
+/// assert_eq!(statement.build(), "SHOW CHANGES FOR TABLE reading SINCE 0;");
 /// ```
 pub fn show_changes_for_table(table: impl Into<TableLike>) -> ShowChangesStatement {
     ShowChangesStatement {
@@ -32,15 +38,28 @@ pub fn show_changes_for_table(table: impl Into<TableLike>) -> ShowChangesStateme
 /// Represents the initial state for building a SHOW CHANGES statement.
 pub struct ShowChangesStatement {
     table: TableLike,
-    since: Option<DatetimeLike>,
+    since: Option<Since>,
     limit: Option<NumberLike>,
 }
 
+/// The starting point of the changes to show.
+enum Since {
+    Timestamp(DatetimeLike),
+    Versionstamp(u64),
+}
+
 impl ShowChangesStatement {
-    /// Sets the SINCE clause for the SHOW CHANGES statement.
+    /// Sets the SINCE clause for the SHOW CHANGES statement using a timestamp.
     pub fn since(mut self, timestamp: impl Into<DatetimeLike>) -> ShowChangesStatement {
         let timestamp: DatetimeLike = timestamp.into();
-        self.since = Some(timestamp);
+        self.since = Some(Since::Timestamp(timestamp));
+        self
+    }
+
+    /// Sets the SINCE clause for the SHOW CHANGES statement using a versionstamp.
+    /// Useful for resuming from the versionstamp of the last consumed change set.
+    pub fn since_versionstamp(mut self, versionstamp: u64) -> ShowChangesStatement {
+        self.since = Some(Since::Versionstamp(versionstamp));
         self
     }
 
@@ -50,16 +69,81 @@ impl ShowChangesStatement {
         self.limit = Some(limit);
         self
     }
+
+    /// Runs the statement and decodes the change feed into typed change sets.
+    /// Each change set carries the versionstamp of the transaction and the
+    /// changes made to the records of the table within it.
+    ///
+    /// Examples
+    /// ```rust, ignore
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{show_changes_for_table, Change}};
+    ///
+    /// let change_sets = show_changes_for_table(Reading::table())
+    ///     .since_versionstamp(0)
+    ///     .return_changes::<Reading>(db.clone())
+    ///     .await?;
+    ///
+    /// for change_set in change_sets {
+    ///     for change in change_set.changes {
+    ///         match change {
+    ///             Change::Update(reading) => println!("{}: upserted {reading:?}", change_set.versionstamp),
+    ///             Change::Delete { id } => println!("{}: deleted {id}", change_set.versionstamp),
+    ///             Change::DefineTable { name } => println!("table {name} defined"),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn return_changes<T>(
+        &self,
+        db: Surreal<impl Connection>,
+    ) -> SurrealOrmResult<Vec<ChangeSet<T>>>
+    where
+        T: DeserializeOwned,
+    {
+        let mut response = self.run(db).await?;
+        response
+            .take::<Vec<ChangeSet<T>>>(0)
+            .map_err(SurrealOrmError::Deserialization)
+    }
+}
+
+/// A set of changes made within a single transaction as returned by the SHOW CHANGES statement.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeSet<T> {
+    /// The versionstamp of the transaction that made the changes.
+    pub versionstamp: u64,
+    /// The changes made within the transaction.
+    pub changes: Vec<Change<T>>,
+}
+
+/// A single change in a change set.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Change<T> {
+    /// A record was created or updated. Contains the record after the change.
+    Update(T),
+    /// A record was deleted.
+    Delete {
+        /// The id of the deleted record
+        id: sql::Thing,
+    },
+    /// The table was defined.
+    DefineTable {
+        /// The name of the defined table
+        name: String,
+    },
 }
 
 impl Queryable for ShowChangesStatement {}
+impl SingleStatement for ShowChangesStatement {}
 
 impl Erroneous for ShowChangesStatement {
     fn get_errors(&self) -> crate::ErrorList {
         let mut errors = vec![];
         errors.extend(self.table.get_errors());
 
-        if let Some(timestamp) = &self.since {
+        if let Some(Since::Timestamp(timestamp)) = &self.since {
             errors.extend(timestamp.get_errors());
         }
 
@@ -72,27 +156,26 @@ impl Erroneous for ShowChangesStatement {
 
 impl Parametric for ShowChangesStatement {
     fn get_bindings(&self) -> BindingsList {
-        let mut bindings = vec![];
-        bindings.extend(self.table.get_bindings());
-
-        if let Some(timestamp) = &self.since {
-            bindings.extend(timestamp.get_bindings());
-        }
-        if let Some(limit) = &self.limit {
-            bindings.extend(limit.get_bindings());
-        }
-        bindings
+        // All the values are inlined when building the statement.
+        vec![]
     }
 }
 
 impl Buildable for ShowChangesStatement {
     fn build(&self) -> String {
-        let mut query = format!("SHOW CHANGES FOR TABLE {}", self.table.build());
-        if let Some(timestamp) = &self.since {
-            query += &format!(" SINCE \"{}\"", timestamp.build());
+        // The table, SINCE and LIMIT clauses only accept literals, so values are inlined.
+        let mut query = format!("SHOW CHANGES FOR TABLE {}", self.table.to_raw().build());
+        match &self.since {
+            Some(Since::Timestamp(timestamp)) => {
+                query += &format!(" SINCE {}", timestamp.to_raw().build());
+            }
+            Some(Since::Versionstamp(versionstamp)) => {
+                query += &format!(" SINCE {versionstamp}");
+            }
+            None => {}
         }
         if let Some(limit) = &self.limit {
-            query += &format!(" LIMIT {}", limit.build());
+            query += &format!(" LIMIT {}", limit.to_raw().build());
         }
         query + ";"
     }
@@ -113,10 +196,25 @@ mod tests {
     fn test_show_changes_for_table_build() {
         let timestamp = chrono::DateTime::from_timestamp(4343434434, 232).unwrap();
         let statement = show_changes_for_table("reading").since(timestamp).limit(10);
-        assert_eq!(statement.fine_tune_params(), "SHOW CHANGES FOR TABLE $_param_00000001 SINCE \"$_param_00000002\" LIMIT $_param_00000003;");
+        assert_eq!(
+            statement.fine_tune_params(),
+            "SHOW CHANGES FOR TABLE reading SINCE '2107-08-22T05:33:54.000000232Z' LIMIT 10;"
+        );
+        assert!(statement.get_bindings().is_empty());
+        assert_eq!(
+            statement.to_raw().build(),
+            "SHOW CHANGES FOR TABLE reading SINCE '2107-08-22T05:33:54.000000232Z' LIMIT 10;"
+        );
+    }
+
+    #[test]
+    fn test_show_changes_for_table_since_versionstamp_build() {
+        let statement = show_changes_for_table("reading")
+            .since_versionstamp(65536)
+            .limit(10);
         assert_eq!(
             statement.to_raw().build(),
-            "SHOW CHANGES FOR TABLE reading SINCE \"'2107-08-22T05:33:54.000000232Z'\" LIMIT 10;"
+            "SHOW CHANGES FOR TABLE reading SINCE 65536 LIMIT 10;"
         );
     }
 }
@@ -8,7 +8,7 @@ use std::fmt::Display;
 
 use crate::{
     traits::BindingsList,
-    traits::{Buildable, Erroneous, Parametric, Queryable},
+    traits::{Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::DurationLike,
 };
 
@@ -45,6 +45,7 @@ impl Buildable for SleepStatement {
 }
 
 impl Queryable for SleepStatement {}
+impl SingleStatement for SleepStatement {}
 impl Erroneous for SleepStatement {}
 
 impl Parametric for SleepStatement {
@@ -115,6 +115,16 @@ where
     }
 }
 
+#[cfg(feature = "surrealdb-v2")]
+impl<T> From<super::UpsertStatement<T>> for Subquery
+where
+    T: Model + Serialize + DeserializeOwned,
+{
+    fn from(statement: super::UpsertStatement<T>) -> Self {
+        statement_to_subquery(statement)
+    }
+}
+
 impl<T> From<DeleteStatement<T>> for Subquery
 where
     T: Model + Serialize + DeserializeOwned,
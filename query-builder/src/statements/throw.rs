@@ -7,7 +7,7 @@
 use std::fmt;
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     StrandLike,
 };
 
@@ -30,6 +30,7 @@ pub struct ThrowStatement {
 }
 
 impl Queryable for ThrowStatement {}
+impl SingleStatement for ThrowStatement {}
 
 impl Erroneous for ThrowStatement {
     fn get_errors(&self) -> crate::ErrorList {
@@ -6,12 +6,15 @@
 
 use std::fmt::{self, Display};
 
-use surrealdb::sql;
+use surrealdb::{sql, Connection, Response, Surreal};
 
 use crate::{
     traits::{BindingsList, Parametric},
-    traits::{Buildable, Erroneous, Queryable},
+    traits::{Buildable, Erroneous, Queryable, SingleStatement},
+    RetryPolicy, Runnable, SurrealOrmError, SurrealOrmResult, ValueLike,
 };
+
+use super::utils_batch::{BatchQuery, Take};
 /// Creates a Transaction statement starting with BEGIN TRANSACTION and ends with COMMIT
 /// TRANSACTION or END TRANSACTION.
 ///
@@ -218,6 +221,255 @@ impl fmt::Display for TransactionCompletion {
     }
 }
 
+/// Runs the statements added in the closure as a single transaction and returns their
+/// typed outputs. Statements are added with `DbTransaction::add`, which takes a statement
+/// turned into a `BatchQuery` e.g with `batch_one`, and returns a handle to its output.
+/// The closure returns the handles, as a single handle, a tuple or a vec of handles, and
+/// they are resolved to the typed outputs once the transaction is committed.
+///
+/// If any statement fails or `THROW`s, the whole transaction is cancelled and
+/// `SurrealOrmError::TransactionFailed` names the statement that failed.
+///
+/// Each added statement must be a single statement, so that its output is at its position
+/// in the transaction.
+///
+/// # Example
+/// ```rust, ignore
+/// let (from, to) = db_transaction(db.clone(), |tx| {
+///     let from = tx.add(
+///         update::<Account>(id1)
+///             .set(acc.balance.decrement_by(300.00))
+///             .batch_one::<Account>(),
+///     );
+///     let to = tx.add(
+///         update::<Account>(id2)
+///             .set(acc.balance.increment_by(300.00))
+///             .batch_one::<Account>(),
+///     );
+///     (from, to)
+/// })
+/// .await?;
+/// ```
+pub async fn db_transaction<F, H>(
+    db: Surreal<impl Connection>,
+    build: F,
+) -> SurrealOrmResult<H::Output>
+where
+    F: FnOnce(&mut DbTransaction) -> H,
+    H: TransactionOutputs,
+{
+    db_transaction_with_retry(db, RetryPolicy::new(1), build).await
+}
+
+/// Same as `db_transaction`, but the transaction is committed again with the given policy
+/// if it fails with a transient error e.g a conflict with a concurrent transaction.
+/// The closure is only called once, and the same statements and bindings are sent on each attempt.
+///
+/// # Example
+/// ```rust, ignore
+/// let (from, to) = db_transaction_with_retry(db.clone(), RetryPolicy::new(5), |tx| {
+///     let from = tx.add(
+///         update::<Account>(id1)
+///             .set(acc.balance.decrement_by(300.00))
+///             .batch_one::<Account>(),
+///     );
+///     let to = tx.add(
+///         update::<Account>(id2)
+///             .set(acc.balance.increment_by(300.00))
+///             .batch_one::<Account>(),
+///     );
+///     (from, to)
+/// })
+/// .await?;
+/// ```
+pub async fn db_transaction_with_retry<F, H>(
+    db: Surreal<impl Connection>,
+    policy: RetryPolicy,
+    build: F,
+) -> SurrealOrmResult<H::Output>
+where
+    F: FnOnce(&mut DbTransaction) -> H,
+    H: TransactionOutputs,
+{
+    let mut transaction = DbTransaction::default();
+    let outputs = build(&mut transaction);
+    let statements = transaction
+        .queries
+        .iter()
+        .map(Buildable::build)
+        .collect::<Vec<_>>();
+    let statement_failed =
+        |index: usize, error: SurrealOrmError| SurrealOrmError::TransactionFailed {
+            index,
+            statement: statements.get(index).cloned().unwrap_or_default(),
+            error: Box::new(error),
+        };
+
+    if let Some((index, query)) = transaction
+        .queries
+        .iter()
+        .enumerate()
+        .find(|(_, query)| !query.get_errors().is_empty())
+    {
+        return Err(statement_failed(
+            index,
+            SurrealOrmError::QueryBuilder(query.get_errors().join(". ")),
+        ));
+    }
+
+    let transaction = transaction.commit_transaction();
+    let (db, transaction, statement_failed) = (&db, &transaction, &statement_failed);
+    let mut response = policy
+        .retry(move || async move {
+            let mut response = transaction.run(db.clone()).await?;
+            match failed_statement(response.take_errors()) {
+                Some((index, error)) => {
+                    Err(statement_failed(index, SurrealOrmError::query_run(error)))
+                }
+                None => Ok(response),
+            }
+        })
+        .await?;
+
+    outputs
+        .resolve(&mut response)
+        .map_err(|(index, error)| statement_failed(index, error))
+}
+
+/// Returns the statement that made a transaction fail. The other statements of a failed
+/// transaction are not executed and only report the transaction failure.
+fn failed_statement<E: std::fmt::Display>(
+    errors: impl IntoIterator<Item = (usize, E)>,
+) -> Option<(usize, E)> {
+    let mut errors = errors.into_iter().collect::<Vec<_>>();
+    errors.sort_by_key(|(index, _)| *index);
+    let position = errors
+        .iter()
+        .position(|(_, error)| !error.to_string().contains("not executed due to a"))
+        .unwrap_or(0);
+
+    (position < errors.len()).then(|| errors.swap_remove(position))
+}
+
+/// The statements of a transaction run with `db_transaction`.
+#[derive(Debug, Clone, Default)]
+pub struct DbTransaction {
+    queries: Vec<ValueLike>,
+}
+
+impl DbTransaction {
+    /// Adds a statement to the transaction and returns a handle to its typed output.
+    pub fn add<O>(&mut self, query: BatchQuery<O>) -> StatementOutput<O> {
+        let index = self.queries.len();
+        self.queries.push(query.query);
+        StatementOutput {
+            index,
+            take: query.take,
+        }
+    }
+
+    /// Adds a statement whose output is not needed to the transaction.
+    /// Only single statements can be added, so that the index of each statement is its
+    /// position in the transaction.
+    pub fn query(&mut self, query: impl SingleStatement) {
+        self.queries.push(ValueLike {
+            string: query.build(),
+            bindings: query.get_bindings(),
+            errors: query.get_errors(),
+        });
+    }
+
+    fn commit_transaction(self) -> TransactionCompletion {
+        let mut bindings = vec![];
+        let mut queries = vec![];
+        for query in self.queries {
+            bindings.extend(query.get_bindings());
+            queries.push(query.build());
+        }
+
+        TransactionCompletion {
+            data: TransactionData {
+                transaction_completion_type: Some(TranctionCompletionType::CommitTransaction),
+                queries,
+                bindings,
+            },
+        }
+    }
+}
+
+/// A handle to the typed output of a statement added to a `DbTransaction`.
+pub struct StatementOutput<O> {
+    index: usize,
+    take: Take<O>,
+}
+
+impl<O> StatementOutput<O> {
+    /// The position of the statement in the transaction.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+/// The handles returned from the closure of `db_transaction`, resolved to their typed outputs
+/// once the transaction is committed.
+pub trait TransactionOutputs {
+    /// The typed outputs.
+    type Output;
+
+    /// Takes the typed outputs from the response.
+    /// Fails with the index of the statement whose output could not be deserialized.
+    fn resolve(self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)>;
+}
+
+impl TransactionOutputs for () {
+    type Output = ();
+
+    fn resolve(self, _response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)> {
+        Ok(())
+    }
+}
+
+impl<O> TransactionOutputs for StatementOutput<O> {
+    type Output = O;
+
+    fn resolve(self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)> {
+        (self.take)(response, self.index).map_err(|error| (self.index, error))
+    }
+}
+
+impl<O> TransactionOutputs for Vec<StatementOutput<O>> {
+    type Output = Vec<O>;
+
+    fn resolve(self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)> {
+        self.into_iter()
+            .map(|output| output.resolve(response))
+            .collect()
+    }
+}
+
+macro_rules! impl_transaction_outputs {
+    ($($output:ident),+) => {
+        impl<$($output: TransactionOutputs),+> TransactionOutputs for ($($output,)+) {
+            type Output = ($($output::Output,)+);
+
+            #[allow(non_snake_case)]
+            fn resolve(self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)> {
+                let ($($output,)+) = self;
+                Ok(($($output.resolve(response)?,)+))
+            }
+        }
+    };
+}
+
+impl_transaction_outputs!(A);
+impl_transaction_outputs!(A, B);
+impl_transaction_outputs!(A, B, C);
+impl_transaction_outputs!(A, B, C, D);
+impl_transaction_outputs!(A, B, C, D, E);
+impl_transaction_outputs!(A, B, C, D, E, F);
+impl_transaction_outputs!(A, B, C, D, E, F, G);
+impl_transaction_outputs!(A, B, C, D, E, F, G, H);
+
 #[cfg(test)]
 mod tests {
     use select::CanOrder;
@@ -301,4 +553,44 @@ mod tests {
         insta::assert_snapshot!(transaction.fine_tune_params());
         insta::assert_snapshot!(transaction.to_raw().build());
     }
+
+    #[test]
+    fn test_db_transaction_statements_are_committed_in_order() {
+        let user = Table::new("user");
+        let mut transaction = DbTransaction::default();
+        let users = transaction.add(select(All).from(&user).batch_many::<serde_json::Value>());
+        transaction.query(select(All).from(&user).limit(1));
+        let first = transaction.add(select(All).from(&user).batch_first::<serde_json::Value>());
+
+        assert_eq!(users.index(), 0);
+        assert_eq!(first.index(), 2);
+        assert_eq!(
+            transaction.commit_transaction().to_raw().build(),
+            "BEGIN TRANSACTION;\n\n\
+            SELECT * FROM user;\n\n\
+            SELECT * FROM user LIMIT 1;\n\n\
+            SELECT * FROM user;\n\n\
+            COMMIT TRANSACTION;\n\t"
+        );
+    }
+
+    #[test]
+    fn test_failed_statement_skips_statements_not_executed() {
+        let not_executed = "The query was not executed due to a failed transaction".to_string();
+        let errors = vec![
+            (2, not_executed.clone()),
+            (1, "An error occurred: Oops".to_string()),
+            (0, not_executed.clone()),
+        ];
+
+        assert_eq!(
+            failed_statement(errors),
+            Some((1, "An error occurred: Oops".to_string()))
+        );
+        assert_eq!(
+            failed_statement(vec![(3, not_executed.clone())]),
+            Some((3, not_executed))
+        );
+        assert_eq!(failed_statement(Vec::<(usize, String)>::new()), None);
+    }
 }
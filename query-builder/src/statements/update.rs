@@ -24,8 +24,8 @@ use surrealdb::sql;
 use crate::{
     derive_binding_and_errors_from_value, Binding, BindingsList, Buildable, Conditional,
     DurationLike, Erroneous, ErrorList, Filter, Model, Parametric, PatchOp, Queryable, ReturnType,
-    ReturnableDefault, ReturnableStandard, Setter, SurrealId, SurrealSimpleId, SurrealUlid,
-    SurrealUuid, ToRaw,
+    ReturnableDefault, ReturnableStandard, Setter, SingleStatement, SurrealId, SurrealSimpleId,
+    SurrealUlid, SurrealUuid, ToRaw,
 };
 
 /// Creates a new UPDATE statement.
@@ -225,6 +225,7 @@ where
 }
 
 impl<T> Queryable for UpdateStatement<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> SingleStatement for UpdateStatement<T> where T: Serialize + DeserializeOwned + Model {}
 impl<T> Erroneous for UpdateStatement<T>
 where
     T: Serialize + DeserializeOwned + Model,
@@ -0,0 +1,463 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Statement syntax
+// UPSERT [ ONLY ] @targets
+// 	[ CONTENT @value
+// 	  | MERGE @value
+// 	  | REPLACE @value
+// 	  | PATCH @value
+// 	  | SET @field = @value ...
+// 	]
+// 	[ WHERE @condition ]
+// 	[ RETURN [ NONE | BEFORE | AFTER | DIFF | @projections ... ]
+// 	[ TIMEOUT @duration ]
+// 	[ PARALLEL ]
+// ;
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    derive_binding_and_errors_from_value, statements::update::TargettablesForUpdate, Binding,
+    BindingsList, Buildable, Conditional, DurationLike, Erroneous, ErrorList, Filter, Model,
+    Parametric, PatchOp, Queryable, ReturnType, ReturnableDefault, ReturnableStandard, Setter,
+    SingleStatement, ToRaw,
+};
+
+/// Creates a new UPSERT statement.
+/// The UPSERT statement can be used to insert records or modify records if they already exist.
+/// Unlike UPDATE, UPSERT creates the record when the target record id does not yet exist.
+///
+/// # Arguments
+///
+/// * `targettables` - The table or record id to upsert. Can be a model instance, a table, or
+///     a record id.
+/// # Examples
+///
+/// ```rust, ignore
+/// # use surreal_query_builder as surreal_orm;
+/// use std::time::Duration;
+/// use surreal_orm::{*, statements::upsert};
+///
+/// // Upsert using set method
+/// upsert::<User>(user_id)
+///     .set([name.equal_to("Oyelowo"), score.increment_by(5)])
+///     .return_type(ReturnType::After);
+///
+/// // Upsert a full record using content method
+/// upsert::<User>(user_id)
+///     .content(
+///          User {
+///             name: "Oyelowo".into(),
+///             age: 198,
+///             ...
+///          }
+///     );
+///
+/// // Upsert many records that match the filter using merge method in user table
+/// upsert::<User>(user)
+///     .merge(
+///          UserDocument {
+///             hobbies: vec!["music production", "problem solving", "rust"],
+///             ...
+///          }
+///     ).where_(cond(age.greater_than(18)).and(name.like("codebreather")));
+/// ```
+pub fn upsert<T>(targettables: impl Into<TargettablesForUpdate>) -> UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    let table_name = T::table();
+    let targettables: TargettablesForUpdate = targettables.into();
+    let mut bindings = vec![];
+    let mut errors = vec![];
+    let param = match targettables {
+        TargettablesForUpdate::Table(table) => {
+            let table = table.to_string();
+            if table != table_name.to_string() {
+                errors.push(format!(
+                    "table name -{table} does not match the surreal model struct type which belongs to {table_name} table"
+                ));
+            }
+            table
+        }
+        TargettablesForUpdate::SurrealId(id) => {
+            if !id
+                .to_string()
+                .starts_with(format!("{table_name}:").as_str())
+            {
+                errors.push(format!(
+                    "id - {id} does not belong to {table_name} table from the surreal model struct provided"
+                ));
+            }
+            let binding = Binding::new(id);
+            let param = binding.get_param_dollarised();
+            bindings.push(binding);
+            param
+        }
+    };
+
+    UpsertStatementInit {
+        target: param,
+        is_only: false,
+        content: None,
+        merge: None,
+        replace: None,
+        patch_ops: vec![],
+        set: vec![],
+        where_: None,
+        return_type: None,
+        timeout: None,
+        parallel: false,
+        bindings,
+        errors,
+        __model_return_type: PhantomData,
+    }
+}
+
+/// Creates a new UPSERT statement and returns a single object.
+/// The UPSERT statement can be used to insert records or modify records if they already exist.
+///
+/// # Examples
+///
+/// ```rust, ignore
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::upsert_only};
+///
+/// upsert_only::<User>(user_id)
+///     .merge(
+///          UserDocument {
+///             hobbies: vec!["music production", "problem solving", "rust"],
+///             ...
+///          }
+///     );
+/// ```
+pub fn upsert_only<T>(targettables: impl Into<TargettablesForUpdate>) -> UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    let mut statement = upsert(targettables);
+    statement.is_only = true;
+    statement
+}
+
+/// Upsert statement initializer
+#[derive(Debug, Clone)]
+pub struct UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    target: String,
+    is_only: bool,
+    content: Option<String>,
+    merge: Option<String>,
+    replace: Option<String>,
+    set: Vec<String>,
+    patch_ops: Vec<String>,
+    where_: Option<String>,
+    return_type: Option<ReturnType>,
+    timeout: Option<String>,
+    bindings: BindingsList,
+    errors: ErrorList,
+    parallel: bool,
+    __model_return_type: PhantomData<T>,
+}
+
+impl<T> Queryable for UpsertStatement<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> SingleStatement for UpsertStatement<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> Erroneous for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn get_errors(&self) -> ErrorList {
+        self.0.errors.to_vec()
+    }
+}
+
+impl<T> UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Caution! Overrides all data even with default. Use with care.
+    /// Specify the full record data using the CONTENT keyword. The content must be serializable
+    /// and implement Model trait.
+    pub fn content(mut self, content: T) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&content);
+        self.content = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// merge-upsert only specific fields by using the MERGE keyword and specifying only the fields which are to be updated.
+    pub fn merge(mut self, merge: impl Serialize) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&merge);
+        self.merge = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// Caution!
+    /// Fully replaces the record with completely new object and data. This will remove all fields
+    /// that are not present in the new object. This is a destructive operation.
+    pub fn replace(mut self, replacement: impl Serialize) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&replacement);
+        self.replace = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// When specifying fields to upsert using the SET clause,
+    /// it is possible to increment and decrement numeric values,
+    /// and add or remove values from arrays.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// // Set multiple fields as an array or vector:
+    /// .set([name.equal_to("Laser"), damage.increment_by(100)]);
+    ///
+    /// // set a single field number. Generates  =
+    /// .set(score.equal_to(5))
+    ///
+    /// // add to an array. Generates  +=
+    /// .set(friends_names.append("Oyelowo"))
+    /// ```
+    pub fn set(mut self, settables: impl Into<Vec<Setter>>) -> UpsertStatement<T> {
+        let settable: Vec<Setter> = settables.into();
+
+        let (settable, bindings) = settable.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut settable, mut bindings), s| {
+                settable.push(s.build());
+                bindings.extend(s.get_bindings());
+                (settable, bindings)
+            },
+        );
+
+        self.bindings.extend(bindings);
+        self.set.extend(settable);
+        self.into()
+    }
+
+    /// Specify the patch operations to be applied to the record using the PATCH keyword.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// upsert::<User>(user_id).patch([name.patch_replace("Oyelowo"), nickname.patch_remove()]);
+    /// ```
+    pub fn patch(mut self, patch_op: impl Into<Vec<PatchOp>>) -> UpsertStatement<T> {
+        let patch_op: Vec<PatchOp> = patch_op.into();
+        for patch_op in patch_op {
+            self.bindings.extend(patch_op.get_bindings());
+            self.errors.extend(patch_op.get_errors());
+            self.patch_ops.push(patch_op.build());
+        }
+
+        self.into()
+    }
+}
+
+/// A builder for upsert statements.
+pub struct UpsertStatement<T>(UpsertStatementInit<T>)
+where
+    T: Serialize + DeserializeOwned + Model;
+
+impl<T> From<UpsertStatementInit<T>> for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn from(value: UpsertStatementInit<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Adds a condition to the `WHERE` clause of the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// .where_(cond(age.greater_than_or_equal(18)).and(age.less_than_or_equal(90)))
+    /// ```
+    pub fn where_(mut self, condition: impl Conditional) -> Self {
+        self.0.bindings.extend(condition.get_bindings());
+        let condition = Filter::new(condition);
+        self.0.where_ = Some(condition.build());
+        self
+    }
+
+    /// Sets the return type for the query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// statement.return_type(ReturnType::After);
+    /// ```
+    pub fn return_type(mut self, return_type: impl Into<ReturnType>) -> Self {
+        let return_type = return_type.into();
+        self.0.return_type = Some(return_type);
+        self
+    }
+
+    /// Sets the timeout duration for the query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let query = query.timeout(Duration::from_secs(30));
+    /// ```
+    pub fn timeout(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.0.timeout = Some(duration.to_raw().build());
+        self
+    }
+
+    /// Indicates that the query should be executed in parallel.
+    pub fn parallel(mut self) -> Self {
+        self.0.parallel = true;
+        self
+    }
+}
+
+impl<T> Buildable for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn build(&self) -> String {
+        let statement = &self.0;
+        let mut query = "UPSERT".to_string();
+
+        if statement.is_only {
+            query = format!("{query} ONLY");
+        }
+
+        query = format!("{query} {}", statement.target);
+
+        if let Some(content) = &statement.content {
+            query = format!("{query} CONTENT {content}",);
+        } else if let Some(merge) = &statement.merge {
+            query = format!("{query} MERGE {merge}");
+        } else if let Some(replace) = &statement.replace {
+            query = format!("{query} REPLACE {replace}");
+        } else if !statement.set.is_empty() {
+            let set_vec = statement.set.join(", ");
+            query = format!("{query} SET {set_vec}");
+        } else if !statement.patch_ops.is_empty() {
+            let patch_vec = statement.patch_ops.join(", ");
+            query = format!("{query} PATCH [{patch_vec}]");
+        }
+
+        if let Some(condition) = &statement.where_ {
+            query = format!("{query} WHERE {condition}");
+        }
+
+        if let Some(return_type) = &statement.return_type {
+            query = format!("{query} {return_type}");
+        }
+
+        if let Some(timeout) = &statement.timeout {
+            query = format!("{query} TIMEOUT {timeout}");
+        }
+
+        if statement.parallel {
+            query.push_str(" PARALLEL");
+        }
+
+        format!("{query};")
+    }
+}
+
+impl<T> std::fmt::Display for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl<T> Parametric for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn get_bindings(&self) -> BindingsList {
+        self.0.bindings.to_vec()
+    }
+}
+
+impl<T> ReturnableDefault<T> for UpsertStatement<T> where T: Serialize + DeserializeOwned + Model {}
+
+impl<T> ReturnableStandard<T> for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model + Send + Sync,
+{
+    fn set_return_type(mut self, return_type: ReturnType) -> Self {
+        self.0.return_type = Some(return_type);
+        self
+    }
+
+    fn get_return_type(&self) -> ReturnType {
+        self.0.return_type.clone().unwrap_or(ReturnType::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Field, Operatable, Table, TestUser};
+    use std::time::Duration;
+
+    #[test]
+    fn test_upsert_merge_on_record_id() {
+        let statement = upsert::<TestUser>(TestUser::create_id("oyelowo"))
+            .merge(serde_json::json!({ "name": "Oyelowo" }))
+            .return_type(ReturnType::After)
+            .timeout(Duration::from_secs(5))
+            .parallel();
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "UPSERT $_param_00000001 MERGE $_param_00000002 RETURN AFTER  TIMEOUT 5s PARALLEL;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "UPSERT user:oyelowo MERGE { name: 'Oyelowo' } RETURN AFTER  TIMEOUT 5s PARALLEL;"
+        );
+        assert_eq!(statement.get_errors().len(), 0);
+    }
+
+    #[test]
+    fn test_upsert_only_replace_with_condition() {
+        let age = Field::new("age");
+        let statement = upsert_only::<TestUser>(Table::new("user"))
+            .replace(serde_json::json!({ "age": 18 }))
+            .where_(age.less_than(18));
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "UPSERT ONLY user REPLACE $_param_00000001 WHERE age < $_param_00000002;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "UPSERT ONLY user REPLACE { age: 18 } WHERE age < 18;"
+        );
+    }
+
+    #[test]
+    fn test_upsert_collects_error_for_wrong_table() {
+        let statement =
+            upsert::<TestUser>(Table::new("weapon")).merge(serde_json::json!({ "name": "Laser" }));
+
+        assert_eq!(statement.get_errors().len(), 1);
+    }
+}
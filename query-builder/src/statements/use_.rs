@@ -7,7 +7,7 @@
 use std::fmt::Display;
 
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable, SingleStatement},
     types::{Database, Namespace},
 };
 
@@ -82,6 +82,7 @@ impl Display for UseStatement {
 }
 
 impl Queryable for UseStatement {}
+impl SingleStatement for UseStatement {}
 
 impl Erroneous for UseStatement {}
 
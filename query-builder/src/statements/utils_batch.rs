@@ -0,0 +1,362 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+use surrealdb::{Connection, Response, Surreal};
+
+use crate::{
+    BatchErrors, BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable, Runnable,
+    SingleStatement, SurrealOrmError, SurrealOrmResult, ValueLike,
+};
+
+use super::utils_chain::{Chainable, QueryChain};
+
+pub(crate) type Take<O> = fn(&mut Response, usize) -> SurrealOrmResult<O>;
+
+/// A single statement of a `QueryBatch` along with how its result is deserialized.
+/// Created with the methods of `Batchable` e.g `batch_many`.
+#[derive(Debug)]
+pub struct BatchQuery<O> {
+    pub(crate) query: ValueLike,
+    pub(crate) take: Take<O>,
+}
+
+impl<O> Clone for BatchQuery<O> {
+    fn clone(&self) -> Self {
+        Self {
+            query: self.query.clone(),
+            take: self.take,
+        }
+    }
+}
+
+impl<O> BatchQuery<O> {
+    fn new(query: impl SingleStatement, take: Take<O>) -> Self {
+        Self {
+            query: ValueLike {
+                string: query.build(),
+                bindings: query.get_bindings(),
+                errors: query.get_errors(),
+            },
+            take,
+        }
+    }
+}
+
+fn take_many<T: DeserializeOwned>(
+    response: &mut Response,
+    index: usize,
+) -> SurrealOrmResult<Vec<T>> {
+    response
+        .take::<Vec<T>>(index)
+        .map_err(SurrealOrmError::Deserialization)
+}
+
+fn take_first<T: DeserializeOwned>(
+    response: &mut Response,
+    index: usize,
+) -> SurrealOrmResult<Option<T>> {
+    Ok(take_many::<T>(response, index)?.into_iter().next())
+}
+
+fn take_one<T: DeserializeOwned>(
+    response: &mut Response,
+    index: usize,
+) -> SurrealOrmResult<Option<T>> {
+    let mut values = take_many::<T>(response, index)?;
+    if values.len() > 1 {
+        return Err(SurrealOrmError::TooManyItemsReturned(1.into()));
+    }
+    Ok(values.pop())
+}
+
+fn take_value<T: DeserializeOwned + Default>(
+    response: &mut Response,
+    index: usize,
+) -> SurrealOrmResult<T> {
+    Ok(response
+        .take::<Option<T>>(index)
+        .map_err(SurrealOrmError::Deserialization)?
+        .unwrap_or_default())
+}
+
+/// Turns a statement into a typed member of a `QueryBatch`.
+/// Only single statements can be batched, so that each result is at its position in the batch.
+pub trait Batchable: SingleStatement + Sized {
+    /// Returns all the records of the statement.
+    fn batch_many<T: DeserializeOwned>(self) -> BatchQuery<Vec<T>> {
+        BatchQuery::new(self, take_many::<T>)
+    }
+
+    /// Returns the first record of the statement.
+    fn batch_first<T: DeserializeOwned>(self) -> BatchQuery<Option<T>> {
+        BatchQuery::new(self, take_first::<T>)
+    }
+
+    /// Returns the only record of the statement.
+    /// Fails if the statement returns more than one record.
+    fn batch_one<T: DeserializeOwned>(self) -> BatchQuery<Option<T>> {
+        BatchQuery::new(self, take_one::<T>)
+    }
+
+    /// Returns the value of the statement e.g a count from `SELECT VALUE`.
+    /// Defaults if the statement returns nothing.
+    fn batch_value<T: DeserializeOwned + Default>(self) -> BatchQuery<T> {
+        BatchQuery::new(self, take_value::<T>)
+    }
+}
+
+impl<Q: SingleStatement> Batchable for Q {}
+
+/// A tuple of `BatchQuery` which can be run as a `QueryBatch`.
+pub trait BatchQueries {
+    /// The tuple of the typed results of the statements.
+    type Output;
+
+    /// The statements of the batch in order.
+    fn queries(&self) -> Vec<&ValueLike>;
+
+    /// Takes the typed results of the statements from the response.
+    /// Fails with the index of the first statement that could not be deserialized.
+    fn take(&self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)>;
+}
+
+macro_rules! impl_batch_queries {
+    ($($index:tt $output:ident),+) => {
+        impl<$($output),+> BatchQueries for ($(BatchQuery<$output>,)+) {
+            type Output = ($($output,)+);
+
+            fn queries(&self) -> Vec<&ValueLike> {
+                vec![$(&self.$index.query),+]
+            }
+
+            fn take(&self, response: &mut Response) -> Result<Self::Output, (usize, SurrealOrmError)> {
+                Ok(($((self.$index.take)(response, $index).map_err(|error| ($index, error))?,)+))
+            }
+        }
+    };
+}
+
+impl_batch_queries!(0 A);
+impl_batch_queries!(0 A, 1 B);
+impl_batch_queries!(0 A, 1 B, 2 C);
+impl_batch_queries!(0 A, 1 B, 2 C, 3 D);
+impl_batch_queries!(0 A, 1 B, 2 C, 3 D, 4 E);
+impl_batch_queries!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+impl_batch_queries!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+impl_batch_queries!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+
+/// Batches statements together to run them in a single round trip and get their typed
+/// results back as a tuple. Also aliased as `query_batch!`.
+///
+/// # Arguments
+///
+/// * `queries` - A tuple of up to 8 statements, each turned into a `BatchQuery` with
+///   `batch_many`, `batch_first`, `batch_one` or `batch_value`.
+///
+/// # Example
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::{select, select_value}};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// let user = Table::new("user");
+/// let age = Field::new("age");
+/// let batch = batch((
+///     select(All).from(&user).where_(age.gt(18)).batch_many::<User>(),
+///     select(All).from(&user).order_by(age.desc()).batch_first::<User>(),
+///     select_value(Field::new("count"))
+///         .from(select(count!()).from(&user).group_all())
+///         .batch_value::<u64>(),
+/// ));
+///
+/// assert_eq!(
+///     batch.to_raw().build(),
+///     "SELECT * FROM user WHERE age > 18;\n\n\
+///     SELECT * FROM user ORDER BY age DESC;\n\n\
+///     SELECT VALUE count FROM (SELECT count() FROM user GROUP ALL);"
+/// );
+///
+/// // let (adults, oldest, total) = batch.return_all(db.clone()).await?;
+/// ```
+pub fn batch<B: BatchQueries>(queries: B) -> QueryBatch<B> {
+    let chain = QueryChain::from(
+        queries
+            .queries()
+            .into_iter()
+            .cloned()
+            .map(Chainable::from)
+            .collect::<Vec<_>>(),
+    );
+    QueryBatch { queries, chain }
+}
+
+/// Batches statements together to run them in a single round trip and get their typed
+/// results back as a tuple. See `batch`.
+///
+/// # Example
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::select};
+///
+/// let user = Table::new("user");
+/// let batch = query_batch!(
+///     select(All).from(&user).batch_many::<serde_json::Value>(),
+///     select(All).from(&user).limit(1).batch_first::<serde_json::Value>(),
+/// );
+///
+/// assert_eq!(
+///     batch.fine_tune_params(),
+///     "SELECT * FROM user;\n\nSELECT * FROM user LIMIT $_param_00000001;"
+/// );
+/// ```
+#[macro_export]
+macro_rules! query_batch {
+    ($($query:expr),+ $(,)?) => {
+        $crate::statements::utils::batch(($($query,)+))
+    };
+}
+
+/// Statements run in a single round trip with their typed results returned as a tuple.
+/// Created with `batch` or `query_batch!`.
+#[derive(Debug, Clone)]
+pub struct QueryBatch<B> {
+    queries: B,
+    chain: QueryChain,
+}
+
+impl<B> QueryBatch<B>
+where
+    B: BatchQueries + Send + Sync,
+{
+    /// Runs the statements against the database in a single request and returns their
+    /// typed results as a tuple in the order of the statements.
+    ///
+    /// Fails with `SurrealOrmError::QueryBatch`, holding the errors by statement index,
+    /// if any of the statements is invalid, fails in the database or cannot be deserialized.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let (users, order, total): (Vec<User>, Option<Order>, u64) = query_batch!(
+    ///     select(All).from(User::table()).batch_many::<User>(),
+    ///     select(All).from(order_id).batch_one::<Order>(),
+    ///     User::count_all().batch_value::<u64>(),
+    /// )
+    /// .return_all(db.clone())
+    /// .await?;
+    /// ```
+    pub async fn return_all(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<B::Output> {
+        let builder_errors = self
+            .queries
+            .queries()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, query)| !query.get_errors().is_empty())
+            .map(|(index, query)| (index, query.get_errors().join(". ")))
+            .collect::<BatchErrors>();
+        if !builder_errors.is_empty() {
+            return Err(SurrealOrmError::QueryBatch(builder_errors));
+        }
+
+        let mut response = self.run(db).await?;
+        let errors = response
+            .take_errors()
+            .into_iter()
+            .map(|(index, error)| (index, error.to_string()))
+            .collect::<BatchErrors>();
+        if !errors.is_empty() {
+            return Err(SurrealOrmError::QueryBatch(errors));
+        }
+
+        self.queries.take(&mut response).map_err(|(index, error)| {
+            SurrealOrmError::QueryBatch(BatchErrors::from_iter([(index, error.to_string())]))
+        })
+    }
+}
+
+impl<B> Buildable for QueryBatch<B> {
+    fn build(&self) -> String {
+        self.chain.build()
+    }
+}
+
+impl<B> Parametric for QueryBatch<B> {
+    fn get_bindings(&self) -> BindingsList {
+        self.chain.get_bindings()
+    }
+}
+
+impl<B> Erroneous for QueryBatch<B> {
+    fn get_errors(&self) -> ErrorList {
+        self.chain.get_errors()
+    }
+}
+
+impl<B> Queryable for QueryBatch<B> {}
+
+impl<B> fmt::Display for QueryBatch<B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        statements::{select, Cursor},
+        All, Field, Operatable, Table, ToRaw,
+    };
+
+    #[test]
+    fn test_batch_chains_statements_in_order() {
+        let user = Table::new("user");
+        let age = Field::new("age");
+        let batch = query_batch!(
+            select(All)
+                .from(&user)
+                .where_(age.gt(18))
+                .batch_many::<serde_json::Value>(),
+            select(All).from(&user).batch_first::<serde_json::Value>(),
+            select(All).from(&user).batch_one::<serde_json::Value>(),
+        );
+
+        assert_eq!(batch.queries.queries().len(), 3);
+        assert_eq!(
+            batch.fine_tune_params(),
+            "SELECT * FROM user WHERE age > $_param_00000001;\n\n\
+            SELECT * FROM user;\n\n\
+            SELECT * FROM user;"
+        );
+        assert_eq!(
+            batch.to_raw().build(),
+            "SELECT * FROM user WHERE age > 18;\n\nSELECT * FROM user;\n\nSELECT * FROM user;"
+        );
+    }
+
+    #[test]
+    fn test_batch_collects_builder_errors() {
+        let user = Table::new("user");
+        let batch = batch((
+            select(All).from(&user).batch_many::<serde_json::Value>(),
+            select(All)
+                .from(&user)
+                .paginate_after(Cursor::from("invalid"), 10)
+                .batch_many::<serde_json::Value>(),
+        ));
+
+        assert_eq!(batch.get_errors().len(), 1);
+    }
+}
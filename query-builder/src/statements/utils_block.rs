@@ -4,7 +4,7 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use crate::{Buildable, Erroneous, Parametric, QueryChain, Queryable, ValueLike};
+use crate::{Buildable, Erroneous, Parametric, QueryChain, Queryable, SingleStatement, ValueLike};
 
 #[macro_export]
 /// Macro for creating a surrealdb code block
@@ -498,6 +498,7 @@ impl From<Block> for ValueLike {
 }
 
 impl Queryable for Block {}
+impl SingleStatement for Block {}
 
 impl Parametric for Block {
     fn get_bindings(&self) -> crate::BindingsList {
@@ -15,6 +15,8 @@ pub struct Binding {
     original_inline_name: String,
     raw_string: String,
     description: Option<String>,
+    #[serde(skip)]
+    is_raw: bool,
 }
 
 #[doc(hidden)]
@@ -32,12 +34,14 @@ impl Binding {
             original_inline_name: param_name.clone(),
             raw_string: value_string,
             description: None,
+            is_raw: false,
         }
     }
 
     // as value as raw
     pub fn as_raw(mut self) -> Self {
         self.raw_string = self.value.to_raw_string();
+        self.is_raw = true;
         self
     }
 
@@ -50,8 +54,17 @@ impl Binding {
         param
     }
 
+    pub(crate) fn with_param(mut self, param: String) -> Self {
+        if self.original_inline_name == self.param {
+            self.original_inline_name = param.clone();
+        }
+        self.param = param;
+        self
+    }
+
     pub fn with_raw(mut self, raw_string: String) -> Self {
         self.raw_string = raw_string;
+        self.is_raw = true;
         self
     }
 
@@ -88,6 +101,15 @@ impl Binding {
     pub fn get_value(&self) -> &sql::Value {
         &self.value
     }
+
+    /// The value as a SurrealQL literal, or the raw string if the binding is raw.
+    pub fn to_surql_literal(&self) -> String {
+        if self.is_raw {
+            self.raw_string.clone()
+        } else {
+            self.value.to_string()
+        }
+    }
 }
 
 impl From<sql::Value> for Binding {
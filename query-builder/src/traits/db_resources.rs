@@ -34,6 +34,13 @@ pub trait DbResources {
         vec![]
     }
 
+    /// Returns a list of access methods.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    fn accesses(&self) -> Vec<Raw> {
+        vec![]
+    }
+
     /// Returns a list of fields.
     fn tokens(&self) -> Vec<Raw> {
         vec![]
@@ -4,9 +4,11 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
+use std::collections::HashMap;
+
 use crate::Field;
 
-use super::{Erroneous, Parametric};
+use super::{param_naming::GENERATED_PARAM, Erroneous, Parametric};
 
 /// A trait for building a query string
 pub trait Buildable {
@@ -16,12 +18,12 @@ pub trait Buildable {
     /// Make query string param consistent. Useful in testing.
     fn fine_tune_params(&self) -> String {
         let mut count = 0;
-        let re = regex::Regex::new(r"_param_[[:xdigit:]]+").unwrap();
-        re.replace_all(&self.build(), |_caps: &regex::Captures<'_>| {
-            count += 1;
-            format!("_param_{:08}", count)
-        })
-        .to_string()
+        GENERATED_PARAM
+            .replace_all(&self.build(), |_caps: &regex::Captures<'_>| {
+                count += 1;
+                format!("$_param_{:08}", count)
+            })
+            .to_string()
     }
 }
 
@@ -42,7 +44,52 @@ where
 }
 
 /// Used for statements
-pub trait Queryable: Parametric + Buildable + Erroneous {}
+pub trait Queryable: Parametric + Buildable + Erroneous {
+    /// Renders the query with every bound value inlined as an escaped SurrealQL literal,
+    /// e.g for pasting a logged query into the Surrealist console. Params that are not bound
+    /// by the query itself, such as `$auth` or those defined with `LET`, are left as they are.
+    ///
+    /// Unlike `to_raw`, the params are replaced in a single pass, so a value that
+    /// happens to contain a param name is never substituted again.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::select};
+    ///
+    /// let name = Field::new("name");
+    /// let statement = select(All)
+    ///     .from(Table::new("user"))
+    ///     .where_(name.eq("O'Reilly"))
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     statement.to_inlined_surql(),
+    ///     "SELECT * FROM user WHERE name = \"O'Reilly\" LIMIT 10;"
+    /// );
+    /// ```
+    fn to_inlined_surql(&self) -> String {
+        let bindings = self
+            .get_bindings()
+            .into_iter()
+            .map(|binding| (binding.get_param().to_string(), binding.to_surql_literal()))
+            .collect::<HashMap<_, _>>();
+
+        GENERATED_PARAM
+            .replace_all(&self.build(), |caps: &regex::Captures<'_>| {
+                bindings
+                    .get(&caps[1])
+                    .cloned()
+                    .unwrap_or_else(|| caps[0].to_string())
+            })
+            .to_string()
+    }
+}
+
+/// Marks a statement which is built as exactly one SurrealQL statement, so that it has
+/// exactly one result in the response. Chains, transactions and raw queries are not,
+/// as they may hold any number of statements.
+pub trait SingleStatement: Queryable {}
 
 /// Used for filters
 pub trait Conditional: Parametric + Buildable + Erroneous {
@@ -54,3 +101,63 @@ pub trait Conditional: Parametric + Buildable + Erroneous {
 
 /// Used for marking a struct used in UPDATE MERGE statement.
 pub trait DataUpdater {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cond,
+        statements::{remove_analyzer, select, select_value},
+        All, Operatable, Param, Table, ToRaw,
+    };
+
+    #[test]
+    fn test_to_inlined_surql_escapes_values_and_keeps_unbound_params() {
+        let name = Field::new("name");
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(cond(name.eq("it's a \"quote\"")).or(name.eq(Param::new("auth"))));
+
+        assert_eq!(
+            statement.to_inlined_surql(),
+            r#"SELECT * FROM user WHERE (name = "it's a \"quote\"") OR (name = $auth);"#
+        );
+    }
+
+    #[test]
+    fn test_to_inlined_surql_does_not_substitute_inside_values() {
+        let name = Field::new("name");
+        let age = Field::new("age");
+        let adult = age.gt(18);
+        let age_param = adult.get_bindings()[0].get_param_dollarised();
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(cond(adult).and(name.eq(age_param.clone())));
+
+        assert_eq!(
+            statement.to_inlined_surql(),
+            format!("SELECT * FROM user WHERE (age > 18) AND (name = '{age_param}');")
+        );
+    }
+
+    #[test]
+    fn test_to_inlined_surql_with_subquery_and_raw_bindings() {
+        let strength = Field::new("strength");
+        let statement = select(All).from(Table::new("weapon")).where_(
+            strength.inside(
+                select_value(strength)
+                    .from(Table::new("weapon"))
+                    .where_(strength.gt(5)),
+            ),
+        );
+        assert_eq!(
+            statement.to_inlined_surql(),
+            "SELECT * FROM weapon WHERE strength INSIDE \
+            (SELECT VALUE strength FROM weapon WHERE strength > 5);"
+        );
+        assert_eq!(statement.to_inlined_surql(), statement.to_raw().build());
+
+        let statement = remove_analyzer("ascii");
+        assert_eq!(statement.to_inlined_surql(), "REMOVE ANALYZER ascii;");
+    }
+}
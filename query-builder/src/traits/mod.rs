@@ -8,10 +8,13 @@ pub(crate) mod binding;
 pub(crate) mod db_resources;
 pub(crate) mod general;
 pub(crate) mod model;
+pub(crate) mod observer;
 pub(crate) mod operation;
+pub(crate) mod param_naming;
 pub(crate) mod patch_op;
 pub(crate) mod pickable;
 pub(crate) mod raw;
+pub(crate) mod retry;
 pub(crate) mod setter;
 pub(crate) mod statements;
 pub(crate) mod table_resources;
@@ -20,10 +23,13 @@ pub use binding::*;
 pub use db_resources::*;
 pub use general::*;
 pub use model::*;
+pub use observer::*;
 pub use operation::*;
+pub use param_naming::*;
 pub use patch_op::*;
 pub use pickable::*;
 pub use raw::*;
+pub use retry::*;
 pub use setter::*;
 pub use statements::*;
 pub use table_resources::*;
@@ -116,6 +116,16 @@ pub trait SurrealCrud: Sized + Serialize + DeserializeOwned + Model {
         update::<Self>(self.get_id_as_thing()).content(self)
     }
 
+    /// Creates or updates a model/table in the database using the UPSERT statement.
+    /// Unlike `save`, this does not rely on UPDATE creating missing records
+    /// which is no longer the case from SurrealDB 2.0.
+    /// UPSERT requires SurrealDB 2.0, so this is only available with the `surrealdb-v2` feature.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    fn save_upsert(self) -> crate::statements::UpsertStatement<Self> {
+        crate::statements::upsert::<Self>(self.get_id_as_thing()).content(self)
+    }
+
     /// Finds a record by id.
     fn find_by_id(id: impl Into<Thing>) -> SelectStatementMini<Self> {
         select(All).from(id.into()).into()
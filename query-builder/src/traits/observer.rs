@@ -0,0 +1,243 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+use surrealdb::Response;
+
+use super::BindingsList;
+use crate::SurrealOrmResult;
+
+/// A run of a query against the database, reported to the registered `QueryObserver`s.
+#[derive(Debug, Clone)]
+pub struct QueryEvent {
+    /// The query as sent to the database, with its bind parameters.
+    pub query: String,
+    /// The names of the bind parameters e.g `$_param_00000001`.
+    pub bindings: Vec<String>,
+    /// How long the query took, including the round trip to the database.
+    pub duration: Duration,
+    /// The number of statement results returned, if the query succeeded.
+    pub result_count: Option<usize>,
+    /// The error, if the query or one of its statements failed. The errors of statements are
+    /// only reported when the results are returned e.g with `return_many`, as `run` leaves them
+    /// in the response for the caller.
+    pub error: Option<String>,
+    /// Whether the query took longer than the slow query threshold.
+    pub slow: bool,
+}
+
+/// Observes every query run against the database e.g to log slow queries or collect metrics.
+/// Registered with `register_query_observer`. Closures taking a `&QueryEvent` are observers.
+pub trait QueryObserver: Send + Sync {
+    /// Called after every query run, whether it succeeded or not.
+    fn on_query(&self, event: &QueryEvent);
+}
+
+impl<F> QueryObserver for F
+where
+    F: Fn(&QueryEvent) + Send + Sync,
+{
+    fn on_query(&self, event: &QueryEvent) {
+        self(event)
+    }
+}
+
+static QUERY_OBSERVERS: RwLock<Vec<Arc<dyn QueryObserver>>> = RwLock::new(Vec::new());
+static SLOW_QUERY_THRESHOLD_MICROS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+/// Registers an observer notified of every query run against the database.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use std::time::Duration;
+/// use surreal_orm::*;
+///
+/// set_slow_query_threshold(Some(Duration::from_millis(100)));
+/// register_query_observer(|event: &QueryEvent| {
+///     if event.slow {
+///         eprintln!("Slow query ({:?}): {}", event.duration, event.query);
+///     }
+/// });
+/// # clear_query_observers();
+/// ```
+pub fn register_query_observer(observer: impl QueryObserver + 'static) {
+    QUERY_OBSERVERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(Arc::new(observer));
+}
+
+/// Removes all the registered query observers.
+pub fn clear_query_observers() {
+    QUERY_OBSERVERS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// Sets the duration above which queries are reported as slow. No query is slow by default.
+pub fn set_slow_query_threshold(threshold: Option<Duration>) {
+    let threshold = threshold.map_or(u64::MAX, |threshold| {
+        u64::try_from(threshold.as_micros()).unwrap_or(u64::MAX - 1)
+    });
+    SLOW_QUERY_THRESHOLD_MICROS.store(threshold, Ordering::Relaxed);
+}
+
+/// Returns the duration above which queries are reported as slow, if any.
+pub fn slow_query_threshold() -> Option<Duration> {
+    match SLOW_QUERY_THRESHOLD_MICROS.load(Ordering::Relaxed) {
+        u64::MAX => None,
+        threshold => Some(Duration::from_micros(threshold)),
+    }
+}
+
+/// Times the run of the query and reports it to the registered observers,
+/// and with the `tracing` feature, within a span and as a tracing event.
+pub(crate) async fn observe_query(
+    query: &str,
+    bindings: &BindingsList,
+    run: impl Future<Output = SurrealOrmResult<Response>>,
+) -> SurrealOrmResult<Response> {
+    #[cfg(feature = "tracing")]
+    let run = tracing::Instrument::instrument(
+        run,
+        tracing::info_span!("surreal_orm.query", query = %query, bindings = bindings.len()),
+    );
+
+    let started = Instant::now();
+    let result = run.await;
+    let duration = started.elapsed();
+
+    let observers = QUERY_OBSERVERS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+    if observers.is_empty() && !cfg!(feature = "tracing") {
+        return result;
+    }
+
+    let event = QueryEvent {
+        query: query.to_string(),
+        bindings: bindings
+            .iter()
+            .map(|binding| binding.get_param_dollarised())
+            .collect(),
+        duration,
+        result_count: result.as_ref().ok().map(Response::num_statements),
+        error: result.as_ref().err().map(ToString::to_string),
+        slow: slow_query_threshold().is_some_and(|threshold| duration > threshold),
+    };
+
+    #[cfg(feature = "tracing")]
+    trace_query(&event);
+
+    for observer in observers {
+        observer.on_query(&event);
+    }
+
+    result
+}
+
+#[cfg(feature = "tracing")]
+fn trace_query(event: &QueryEvent) {
+    let duration_ms = event.duration.as_secs_f64() * 1000.0;
+    match &event.error {
+        Some(error) => tracing::error!(
+            query = %event.query,
+            duration_ms,
+            error = %error,
+            "query failed"
+        ),
+        None if event.slow => tracing::warn!(
+            query = %event.query,
+            duration_ms,
+            result_count = ?event.result_count,
+            "slow query"
+        ),
+        None => tracing::debug!(
+            query = %event.query,
+            duration_ms,
+            result_count = ?event.result_count,
+            "query run"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Mutex, MutexGuard};
+
+    use super::*;
+    use crate::{
+        statements::select, All, Buildable, Field, Operatable, Parametric, SurrealOrmError, Table,
+    };
+
+    /// The observers and the slow query threshold are global, so the tests using them
+    /// run one at a time and reset them when done.
+    fn global_state() -> MutexGuard<'static, ()> {
+        static GLOBAL_STATE: Mutex<()> = Mutex::new(());
+        GLOBAL_STATE
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[test]
+    fn test_observers_are_notified_of_failed_queries() {
+        let _global_state = global_state();
+        let statement = select(All)
+            .from(Table::new("observed_user"))
+            .where_(Field::new("age").gt(18));
+        let query = statement.build();
+        let events = Arc::new(Mutex::new(vec![]));
+        let recorded = events.clone();
+        register_query_observer(move |event: &QueryEvent| {
+            if event.query == query {
+                recorded.lock().unwrap().push(event.clone());
+            }
+        });
+
+        // Run on a blocking executor, so that the lock on the global state is not held
+        // across an await point.
+        let result = futures::executor::block_on(observe_query(
+            &statement.build(),
+            &statement.get_bindings(),
+            async { Err(SurrealOrmError::Thrown("Oops".into())) },
+        ));
+        clear_query_observers();
+
+        assert!(result.is_err());
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            events[0].bindings,
+            vec![statement.get_bindings()[0].get_param_dollarised()]
+        );
+        assert_eq!(events[0].result_count, None);
+        assert_eq!(
+            events[0].error.as_deref(),
+            Some("An error was thrown: Oops")
+        );
+    }
+
+    #[test]
+    fn test_slow_query_threshold() {
+        let _global_state = global_state();
+        set_slow_query_threshold(Some(Duration::from_millis(250)));
+        assert_eq!(slow_query_threshold(), Some(Duration::from_millis(250)));
+
+        set_slow_query_threshold(None);
+        assert_eq!(slow_query_threshold(), None);
+    }
+}
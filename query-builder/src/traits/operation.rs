@@ -1283,6 +1283,60 @@ pub trait Operatable: Sized + Parametric + Buildable + Erroneous {
         operation
     }
 
+    /// `<|k|>` K-Nearest Neighbours. Checks whether the vector in the field is one of the `k`
+    /// nearest neighbours of the given vector. Uses the vector index defined on the field.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of nearest neighbours to retrieve.
+    /// * `vector` - The vector to compare against. Could be an array, `Field` or `Param`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::*;
+    ///
+    /// # let embedding = Field::new("embedding");
+    /// let query = embedding.knn(3, vec![1, 2, 3]);
+    /// assert_eq!(query.to_raw().build(), "embedding <|3|> [1, 2, 3]");
+    /// ```
+    fn knn<T>(&self, k: u32, vector: T) -> Operation
+    where
+        T: Into<ArrayLike>,
+    {
+        self.generate_query(format!("<|{k}|>"), vector.into())
+    }
+
+    /// `<|k,ef|>` K-Nearest Neighbours using an HNSW index. Same as `knn` but also sets
+    /// the size of the dynamic candidate list (`ef`) used during the search.
+    /// Requires SurrealDB 2.0, so this is only available with the `surrealdb-v2` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of nearest neighbours to retrieve.
+    /// * `ef` - The size of the dynamic candidate list.
+    /// * `vector` - The vector to compare against. Could be an array, `Field` or `Param`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::*;
+    ///
+    /// # let embedding = Field::new("embedding");
+    /// let query = embedding.knn_with_ef(3, 40, vec![1, 2, 3]);
+    /// assert_eq!(query.to_raw().build(), "embedding <|3,40|> [1, 2, 3]");
+    /// ```
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    fn knn_with_ef<T>(&self, k: u32, ef: u32, vector: T) -> Operation
+    where
+        T: Into<ArrayLike>,
+    {
+        self.generate_query(format!("<|{k},{ef}|>"), vector.into())
+    }
+
     /// Check whether the value of the field is between the given lower and upper bounds.
     ///
     /// # Arguments
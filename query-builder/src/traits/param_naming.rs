@@ -0,0 +1,275 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        LazyLock,
+    },
+};
+
+use regex::Regex;
+
+use super::{Binding, BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable};
+
+/// How the bind parameters of a statement are named when it is built or run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParamNaming {
+    /// Random names e.g `$_param_1a2b3c4d`. This is the default.
+    #[default]
+    Random,
+    /// Sequential names in order of appearance in the statement e.g `$_param_00000001`.
+    /// The same statement tree always builds the same query.
+    Sequential,
+    /// Names derived from the bound values e.g `$_param_af63bd4c8601b7df`.
+    /// The same value is always bound to the same name, whatever the statement.
+    Hashed,
+}
+
+static PARAM_NAMING: AtomicU8 = AtomicU8::new(0);
+
+/// Matches the generated bind params of a query e.g `$_param_1a2b3c4d`, capturing the name.
+pub(crate) static GENERATED_PARAM: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\$(_param_[[:xdigit:]]+)").unwrap());
+
+/// Sets how the bind parameters of every statement run against the database are named.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::*;
+///
+/// set_param_naming(ParamNaming::Sequential);
+/// assert_eq!(param_naming(), ParamNaming::Sequential);
+/// # set_param_naming(ParamNaming::Random);
+/// ```
+pub fn set_param_naming(naming: ParamNaming) {
+    let naming = match naming {
+        ParamNaming::Random => 0,
+        ParamNaming::Sequential => 1,
+        ParamNaming::Hashed => 2,
+    };
+    PARAM_NAMING.store(naming, Ordering::Relaxed);
+}
+
+/// Returns how the bind parameters of statements run against the database are named.
+pub fn param_naming() -> ParamNaming {
+    match PARAM_NAMING.load(Ordering::Relaxed) {
+        1 => ParamNaming::Sequential,
+        2 => ParamNaming::Hashed,
+        _ => ParamNaming::Random,
+    }
+}
+
+/// A query with its bind parameters renamed. Created with `with_param_naming`.
+#[derive(Debug, Clone)]
+pub struct NamedQuery {
+    query: String,
+    bindings: BindingsList,
+    errors: ErrorList,
+}
+
+impl Buildable for NamedQuery {
+    fn build(&self) -> String {
+        self.query.clone()
+    }
+}
+
+impl Parametric for NamedQuery {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.clone()
+    }
+}
+
+impl Erroneous for NamedQuery {
+    fn get_errors(&self) -> ErrorList {
+        self.errors.clone()
+    }
+}
+
+impl Queryable for NamedQuery {}
+
+impl Display for NamedQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+/// Renames the bind parameters of a statement tree deterministically.
+pub trait ParamNamer: Parametric + Buildable + Erroneous {
+    /// Returns the query with its bind parameters renamed with the given naming.
+    /// The bindings are renamed accordingly, so the query can still be run or converted to raw.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::select};
+    ///
+    /// let age = Field::new("age");
+    /// let statement = select(All)
+    ///     .from(Table::new("user"))
+    ///     .where_(cond(age.gt(18)).and(age.lt(65)))
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     statement.with_param_naming(ParamNaming::Sequential).build(),
+    ///     "SELECT * FROM user WHERE (age > $_param_00000001) AND (age < $_param_00000002) \
+    ///     LIMIT $_param_00000003;"
+    /// );
+    /// assert_eq!(
+    ///     statement.with_param_naming(ParamNaming::Sequential).build(),
+    ///     statement.with_param_naming(ParamNaming::Sequential).build(),
+    /// );
+    /// ```
+    fn with_param_naming(&self, naming: ParamNaming) -> NamedQuery {
+        let (query, bindings) = rename_params(self.build(), self.get_bindings(), naming);
+
+        NamedQuery {
+            query,
+            bindings,
+            errors: self.get_errors(),
+        }
+    }
+}
+
+impl<T> ParamNamer for T where T: Parametric + Buildable + Erroneous {}
+
+/// Renames the generated params of the query and of the bindings. Bindings are renamed in order
+/// of appearance in the query, followed by the ones not used in the query. Bindings sharing
+/// the same name after renaming are only kept once.
+pub(crate) fn rename_params(
+    query: String,
+    bindings: BindingsList,
+    naming: ParamNaming,
+) -> (String, BindingsList) {
+    if naming == ParamNaming::Random {
+        return (query, bindings);
+    }
+
+    let bindings_by_param = bindings
+        .iter()
+        .map(|binding| (binding.get_param().to_string(), binding))
+        .collect::<HashMap<_, _>>();
+    let ordered = GENERATED_PARAM
+        .captures_iter(&query)
+        .filter_map(|caps| bindings_by_param.get(&caps[1]).copied())
+        .chain(bindings.iter())
+        .collect::<Vec<_>>();
+
+    let mut names = HashMap::<String, String>::new();
+    let mut hashed_values = HashMap::<String, String>::new();
+    let mut renamed = vec![];
+    for binding in ordered {
+        if names.contains_key(binding.get_param()) {
+            continue;
+        }
+        let name = match naming {
+            ParamNaming::Sequential => format!("_param_{:08}", names.len() + 1),
+            _ => hashed_name(binding, &mut hashed_values),
+        };
+        names.insert(binding.get_param().to_string(), name.clone());
+        if !renamed.iter().any(|b: &Binding| b.get_param() == &name) {
+            renamed.push(binding.clone().with_param(name));
+        }
+    }
+
+    let query = GENERATED_PARAM
+        .replace_all(&query, |caps: &regex::Captures<'_>| {
+            names
+                .get(&caps[1])
+                .map(|name| format!("${name}"))
+                .unwrap_or_else(|| caps[0].to_string())
+        })
+        .to_string();
+
+    (query, renamed)
+}
+
+/// Hashes the value of the binding with FNV-1a, which is stable across builds and platforms.
+/// The hash is salted in the unlikely case two different values hash to the same name.
+fn hashed_name(binding: &Binding, hashed_values: &mut HashMap<String, String>) -> String {
+    let value = binding.get_value().to_string();
+    let mut salt = 0_u64;
+    loop {
+        let hash = format!("{value}{}", "\0".repeat(salt as usize))
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+        let name = format!("_param_{hash:016x}");
+        match hashed_values.get(&name) {
+            Some(existing) if existing != &value => salt += 1,
+            _ => {
+                hashed_values.insert(name.clone(), value);
+                return name;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cond, statements::select, All, Field, Operatable, Table, ToRaw};
+
+    #[test]
+    fn test_sequential_names_follow_query_order_and_keep_raw_query() {
+        let age = Field::new("age");
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(age.gt(18))
+            .limit(10)
+            .start(5);
+        let named = statement.with_param_naming(ParamNaming::Sequential);
+
+        assert_eq!(
+            named.build(),
+            "SELECT * FROM user WHERE age > $_param_00000001 LIMIT $_param_00000002 \
+            START AT $_param_00000003;"
+        );
+        assert_eq!(
+            named
+                .get_bindings()
+                .iter()
+                .map(|b| b.get_param().as_str())
+                .collect::<Vec<_>>(),
+            vec!["_param_00000001", "_param_00000002", "_param_00000003"]
+        );
+        assert_eq!(named.to_raw().build(), statement.to_raw().build());
+    }
+
+    #[test]
+    fn test_hashed_names_depend_on_values_only() {
+        let age = Field::new("age");
+        let statement1 = select(All)
+            .from(Table::new("user"))
+            .where_(cond(age.gt(18)).and(age.lt(18)));
+        let statement2 = select(All).from(Table::new("user")).where_(age.eq(18));
+
+        let named1 = statement1.with_param_naming(ParamNaming::Hashed);
+        let named2 = statement2.with_param_naming(ParamNaming::Hashed);
+
+        assert_eq!(named1.get_bindings().len(), 1);
+        let param = named2.get_bindings()[0].get_param_dollarised();
+        assert_eq!(
+            named1.build(),
+            format!("SELECT * FROM user WHERE (age > {param}) AND (age < {param});")
+        );
+        assert_eq!(named1.to_raw().build(), statement1.to_raw().build());
+    }
+
+    #[test]
+    fn test_random_naming_keeps_query_unchanged() {
+        let statement = select(All)
+            .from(Table::new("user"))
+            .where_(Field::new("age").gt(18));
+        let named = statement.with_param_naming(ParamNaming::Random);
+
+        assert_eq!(named.build(), statement.build());
+    }
+}
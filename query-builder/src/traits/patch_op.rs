@@ -1,11 +1,12 @@
 use std::fmt::Display;
 
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 use surrealdb::sql;
 
 use crate::{Binding, BindingsList, Buildable, Erroneous, ErrorList, Field, Parametric};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum OpType {
     /// Adds values along the path using JSON patch operation
     Add,
@@ -70,6 +71,62 @@ impl From<PatchOp> for Vec<PatchOp> {
     }
 }
 
+/// A JSON patch operation as returned by the database e.g from `value::diff`.
+#[derive(Deserialize)]
+struct JsonPatchOp {
+    op: OpType,
+    path: String,
+    // Tells an explicit `null` value, which is kept, from a missing one
+    #[serde(default, deserialize_with = "deserialize_present")]
+    value: Option<Option<serde_json::Value>>,
+}
+
+fn deserialize_present<'de, D>(
+    deserializer: D,
+) -> Result<Option<Option<serde_json::Value>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<serde_json::Value>::deserialize(deserializer).map(Some)
+}
+
+/// Allows patch operations returned by the database e.g from `value::diff`
+/// to be deserialized and passed back to `update().patch(...)`.
+impl<'de> Deserialize<'de> for PatchOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let JsonPatchOp { op, path, value } = JsonPatchOp::deserialize(deserializer)?;
+        let path_binding = Binding::new(sql::Value::from(path));
+        let patch = PatchOpInit {
+            path: path_binding.get_param_dollarised(),
+            op,
+            value: None,
+            bindings: vec![path_binding],
+            errors: vec![],
+        };
+
+        let patch = match value {
+            Some(value) => {
+                let (binding, errors) = match value {
+                    Some(value) => derive_binding_and_errors_from_value(&value),
+                    None => (Binding::new(sql::Value::Null), vec![]),
+                };
+                PatchOpInit {
+                    value: Some(binding.get_param_dollarised()),
+                    bindings: patch.bindings.into_iter().chain(vec![binding]).collect(),
+                    errors,
+                    ..patch
+                }
+            }
+            None => patch,
+        };
+
+        Ok(PatchOp(patch))
+    }
+}
+
 impl Parametric for PatchOp {
     fn get_bindings(&self) -> BindingsList {
         self.0.bindings.to_vec()
@@ -389,4 +446,47 @@ mod tests {
             "{ op: 'add', path: '/name/first/title' }"
         );
     }
+
+    #[test]
+    fn can_deserialize_diff_operations() {
+        let diff = serde_json::json!([
+            { "op": "replace", "path": "/name", "value": "Oyedayo" },
+            { "op": "change", "path": "/bio", "value": "@@ -1,4 +1,4 @@ te-s+x t" },
+            { "op": "remove", "path": "/nickname" },
+        ]);
+
+        let patch_ops: Vec<PatchOp> = serde_json::from_value(diff).unwrap();
+        assert_eq!(patch_ops.len(), 3);
+
+        let built = patch_ops
+            .iter()
+            .map(|op| op.to_raw().build())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            built,
+            vec![
+                "{ op: 'replace', path: '/name', value: 'Oyedayo' }",
+                "{ op: 'change', path: '/bio', value: '@@ -1,4 +1,4 @@ te-s+x t' }",
+                "{ op: 'remove', path: '/nickname' }",
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_null_values_of_diff_operations() {
+        let diff = serde_json::json!({ "op": "replace", "path": "/nickname", "value": null });
+
+        let patch_op: PatchOp = serde_json::from_value(diff).unwrap();
+        assert_eq!(patch_op.get_bindings().len(), 2);
+        assert_eq!(
+            patch_op.to_raw().build(),
+            "{ op: 'replace', path: '/nickname', value: NULL }"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_diff_operations() {
+        let diff = serde_json::json!({ "op": "teleport", "path": "/name" });
+        assert!(serde_json::from_value::<PatchOp>(diff).is_err());
+    }
 }
@@ -0,0 +1,242 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::{future::Future, time::Duration};
+
+use rand::Rng;
+
+use crate::{SurrealOrmError, SurrealOrmResult};
+
+/// How a statement or a transaction is retried when it fails with a transient error,
+/// e.g a transaction conflict between concurrent writers. Other errors are returned right away.
+///
+/// The backoff doubles after every attempt, from `initial_backoff` up to `max_backoff`.
+/// With jitter, a random delay of up to half the backoff is taken off, so that conflicting
+/// writers do not retry in lockstep.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use std::time::Duration;
+/// use surreal_orm::*;
+///
+/// let policy = RetryPolicy::new(5)
+///     .initial_backoff(Duration::from_millis(20))
+///     .max_backoff(Duration::from_millis(100))
+///     .jitter(false);
+///
+/// assert_eq!(policy.backoff(1), Duration::from_millis(20));
+/// assert_eq!(policy.backoff(2), Duration::from_millis(40));
+/// assert_eq!(policy.backoff(4), Duration::from_millis(100));
+///
+/// // let response = statement.run_with_retry(db.clone(), policy).await?;
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_secs(2),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy which makes at most `max_attempts` attempts, including the first one.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the backoff before the first retry. Defaults to 50ms.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Sets the maximum backoff between two attempts. Defaults to 2s.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Sets whether the backoff is randomized. Defaults to true.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the maximum number of attempts, including the first one.
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the backoff before the given retry, starting at 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_backoff);
+
+        if self.jitter {
+            backoff - rand::thread_rng().gen_range(Duration::ZERO..=backoff / 2)
+        } else {
+            backoff
+        }
+    }
+
+    /// Runs the attempt until it succeeds, fails with an error which is not transient
+    /// or the maximum number of attempts is reached, in which case the last error is returned.
+    pub async fn retry<T, F, Fut>(&self, mut attempt: F) -> SurrealOrmResult<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = SurrealOrmResult<T>>,
+    {
+        let mut attempts = 1;
+        loop {
+            match attempt().await {
+                Err(error) if error.is_transient() && attempts < self.max_attempts => {
+                    let backoff = self.backoff(attempts);
+                    tokio::time::sleep(backoff).await;
+                    attempts += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Returns the error of the first failed statement of the response which is transient,
+/// so that the statements are retried, or else the error of the first failed statement.
+pub(crate) fn response_error(
+    errors: impl IntoIterator<Item = (usize, surrealdb::Error)>,
+) -> Option<SurrealOrmError> {
+    let mut errors = errors
+        .into_iter()
+        .map(|(index, error)| (index, SurrealOrmError::query_run(error)))
+        .collect::<Vec<_>>();
+    errors.sort_by_key(|(index, _)| *index);
+
+    match errors.iter().position(|(_, error)| error.is_transient()) {
+        Some(position) => Some(errors.swap_remove(position).1),
+        None => errors.into_iter().next().map(|(_, error)| error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+
+    fn conflict() -> SurrealOrmError {
+        SurrealOrmError::TransactionConflict("This transaction can be retried".into())
+    }
+
+    #[test]
+    fn test_backoff_is_capped_and_jittered() {
+        let policy = RetryPolicy::new(10)
+            .initial_backoff(Duration::from_millis(10))
+            .max_backoff(Duration::from_millis(50));
+
+        for retry in 1..10 {
+            let backoff = policy.backoff(retry);
+            let expected = policy.jitter(false).backoff(retry);
+            assert!(backoff <= expected && backoff >= expected / 2);
+        }
+        assert_eq!(policy.jitter(false).backoff(3), Duration::from_millis(40));
+        assert_eq!(
+            policy.jitter(false).backoff(u32::MAX),
+            Duration::from_millis(50)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_only_transient_errors_up_to_max_attempts() {
+        let policy = RetryPolicy::new(3).initial_backoff(Duration::from_millis(1));
+
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(conflict())
+            })
+            .await;
+        assert!(matches!(
+            result,
+            Err(SurrealOrmError::TransactionConflict(_))
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(SurrealOrmError::Thrown("Oops".into()))
+            })
+            .await;
+        assert!(matches!(result, Err(SurrealOrmError::Thrown(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .retry(|| async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(conflict()),
+                    attempt => Ok(attempt),
+                }
+            })
+            .await;
+        assert!(matches!(result, Ok(1)));
+    }
+
+    #[tokio::test]
+    async fn test_retry_datastore_transaction_conflict() {
+        use surrealdb::error::Db;
+
+        // How the 1.x engine reports a RocksDB conflict: the failed statement gets the
+        // datastore transaction error and the others are not executed.
+        let busy = || Db::Tx("Resource busy: ".into());
+        let response = || {
+            vec![
+                (
+                    1,
+                    surrealdb::Error::Db(Db::QueryNotExecutedDetail {
+                        message: busy().to_string(),
+                    }),
+                ),
+                (0, surrealdb::Error::Db(busy())),
+            ]
+        };
+
+        let error = SurrealOrmError::query_run(surrealdb::Error::Db(busy()));
+        assert!(matches!(error, SurrealOrmError::TransactionConflict(_)));
+        assert!(error.is_transient());
+
+        let policy = RetryPolicy::new(3).initial_backoff(Duration::from_millis(1));
+        let attempts = AtomicU32::new(0);
+        let result = policy
+            .retry(|| async {
+                match attempts.fetch_add(1, Ordering::SeqCst) {
+                    0 => Err(response_error(response()).unwrap()),
+                    attempt => Ok(attempt),
+                }
+            })
+            .await;
+        assert!(matches!(result, Ok(1)));
+    }
+}
@@ -1,4 +1,9 @@
-use super::{Buildable, Parametric};
+use super::{
+    observer::observe_query,
+    param_naming::{param_naming, rename_params},
+    retry::response_error,
+    BindingsList, Buildable, Parametric, RetryPolicy,
+};
 use crate::{
     AllGetter, Field, Model, Projections, Queryable, ReturnType, SurrealOrmError, SurrealOrmResult,
     ValueLike,
@@ -16,25 +21,83 @@ where
     Self: Queryable,
 {
     /// Runs the statement against the database.
+    ///
+    /// The errors of failed statements are left in the response for the caller,
+    /// so they are not reported to the query observers.
     async fn run(
         &self,
         db: Surreal<impl surrealdb::Connection>,
     ) -> SurrealOrmResult<surrealdb::Response> {
-        let query_builder_error = self.get_errors();
+        let (query, bindings) = prepare_query(self)?;
+        let request = bindings.iter().fold(db.query(query.as_str()), |acc, val| {
+            acc.bind((val.get_param(), val.get_value()))
+        });
 
-        if !query_builder_error.is_empty() {
-            return Err(SurrealOrmError::QueryBuilder(
-                query_builder_error.join(". \n"),
-            ));
-        }
+        observe_query(&query, &bindings, async {
+            request.await.map_err(SurrealOrmError::query_run)
+        })
+        .await
+    }
 
-        let query = self.build();
-        let query = db.query(query);
-        let query = self.get_bindings().iter().fold(query, |acc, val| {
+    /// Runs the statement against the database and fails with the error of a failed statement,
+    /// which is reported to the query observers. Used by the methods returning the results.
+    #[doc(hidden)]
+    async fn run_checked(
+        &self,
+        db: Surreal<impl surrealdb::Connection>,
+    ) -> SurrealOrmResult<surrealdb::Response> {
+        let (query, bindings) = prepare_query(self)?;
+        let request = bindings.iter().fold(db.query(query.as_str()), |acc, val| {
             acc.bind((val.get_param(), val.get_value()))
         });
 
-        Ok(query.await.map_err(SurrealOrmError::QueryRun)?)
+        observe_query(&query, &bindings, async {
+            let mut response = request.await.map_err(SurrealOrmError::query_run)?;
+
+            match response_error(response.take_errors()) {
+                Some(error) => Err(error),
+                None => Ok(response),
+            }
+        })
+        .await
+    }
+
+    /// Runs the statement against the database, retrying it with the given policy
+    /// if it fails with a transient error e.g a transaction conflict.
+    /// The query is built once and the same bindings are bound on each attempt.
+    ///
+    /// Unlike `run`, fails if any statement fails, with the error of the first failed statement.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let response = update::<Account>(account_id)
+    ///     .set(balance.increment_by(100.00))
+    ///     .run_with_retry(db.clone(), RetryPolicy::new(5))
+    ///     .await?;
+    /// ```
+    async fn run_with_retry(
+        &self,
+        db: Surreal<impl surrealdb::Connection>,
+        policy: RetryPolicy,
+    ) -> SurrealOrmResult<surrealdb::Response> {
+        let (query, bindings) = prepare_query(self)?;
+        let (db, query, bindings) = (&db, &query, &bindings);
+        policy
+            .retry(move || {
+                let request = bindings.iter().fold(db.query(query.as_str()), |acc, val| {
+                    acc.bind((val.get_param(), val.get_value()))
+                });
+
+                observe_query(query, bindings, async move {
+                    let mut response = request.await.map_err(SurrealOrmError::query_run)?;
+
+                    match response_error(response.take_errors()) {
+                        Some(error) => Err(error),
+                        None => Ok(response),
+                    }
+                })
+            })
+            .await
     }
 
     /// Runs the statement against the database and returns the deserialized result.
@@ -45,7 +108,7 @@ where
     where
         T: Sized + Serialize + DeserializeOwned,
     {
-        let mut response = self.run(db).await?;
+        let mut response = self.run_checked(db).await?;
 
         Ok(response
             .take::<Option<T>>(0)
@@ -55,6 +118,23 @@ where
 
 impl<Q> Runnable for Q where Q: Queryable {}
 
+/// Returns the query to run with its bindings, or the errors of the query builder.
+fn prepare_query<Q: Queryable + ?Sized>(statement: &Q) -> SurrealOrmResult<(String, BindingsList)> {
+    let query_builder_error = statement.get_errors();
+
+    if !query_builder_error.is_empty() {
+        return Err(SurrealOrmError::QueryBuilder(
+            query_builder_error.join(". \n"),
+        ));
+    }
+
+    Ok(rename_params(
+        statement.build(),
+        statement.get_bindings(),
+        param_naming(),
+    ))
+}
+
 /// A trait that represents a statement that can be run against the database and return a single
 #[async_trait::async_trait]
 pub trait ReturnableStandard<T>
@@ -94,7 +174,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = query.run_checked(db).await?;
         get_first::<P>(response)
     }
 
@@ -112,7 +192,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = query.run_checked(db).await?;
         get_one::<P>(response)
     }
 
@@ -130,7 +210,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = query.run_checked(db).await?;
         get_many::<P>(response)
     }
 
@@ -261,7 +341,7 @@ where
 
     /// Runs the statement against the database and returns the one result.
     async fn return_one(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Option<T>> {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_one::<T>(response)
     }
 
@@ -271,7 +351,7 @@ where
     /// even if you select subset, it fills up the rest to make
     /// sure you get the full record and can be properly deserialized.
     async fn get_one(self, db: Surreal<impl Connection>) -> SurrealOrmResult<T> {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         let returned_type = self.get_return_type();
         let all = vec![ValueLike::from(Field::new("*"))];
         let selected_fields = match returned_type {
@@ -296,7 +376,7 @@ where
 
     /// Runs the statement against the database and returns the many results.
     async fn return_many(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Vec<T>> {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_many::<T>(response)
     }
 
@@ -308,7 +388,7 @@ where
 
     /// Runs the statement against the database and returns the first result.
     async fn return_first(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Option<T>> {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_first::<T>(response)
     }
 
@@ -341,7 +421,7 @@ where
     where
         V: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_one::<V>(response)
     }
 
@@ -354,7 +434,7 @@ where
     where
         V: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_many::<V>(response)
     }
 }
@@ -377,7 +457,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_first::<T>(response)
     }
 
@@ -389,7 +469,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_one::<T>(response)
     }
 
@@ -398,7 +478,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await.unwrap();
+        let response = self.run_checked(db).await.unwrap();
         get_last::<T>(response).unwrap().unwrap()
     }
 
@@ -407,7 +487,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = self.run_checked(db).await?;
         get_many::<T>(response)
     }
 }
@@ -1,3 +1,12 @@
+#[cfg(feature = "surrealdb-v2")]
+use crate::{
+    statements::{
+        rebuild_index,
+        rebuild_index::index_name_and_table,
+        utils::{Chainable, QueryChain},
+    },
+    Buildable, SurrealOrmError, SurrealOrmResult,
+};
 use crate::{Field, Model, Raw, Table};
 
 /// Provides the ability to gather all the resources
@@ -30,6 +39,30 @@ where
     fn table_definition() -> Raw {
         Self::define_table()
     }
+
+    /// Returns a chain of `REBUILD INDEX` statements, one for each index
+    /// in `indexes_definitions`. Useful to refresh search and vector indexes
+    /// after bulk imports e.g `User::rebuild_indexes()?.run(db.clone()).await?`.
+    ///
+    /// Errors if any of the definitions is not an index definition.
+    #[cfg(feature = "surrealdb-v2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "surrealdb-v2")))]
+    fn rebuild_indexes() -> SurrealOrmResult<QueryChain> {
+        Self::indexes_definitions()
+            .iter()
+            .map(|definition| {
+                let definition = definition.build();
+                index_name_and_table(&definition)
+                    .map(|(index, table)| Chainable::from(rebuild_index(index).on_table(table)))
+                    .ok_or_else(|| {
+                        SurrealOrmError::QueryBuilder(format!(
+                            "Cannot rebuild the index of a definition which is not an index definition: {definition}"
+                        ))
+                    })
+            })
+            .collect::<SurrealOrmResult<Vec<_>>>()
+            .map(QueryChain::from)
+    }
 }
 
 /// A trait for field changes.
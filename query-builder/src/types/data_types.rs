@@ -190,6 +190,7 @@ create_value_like_struct!("Duration");
 create_value_like_struct!("Datetime");
 create_value_like_struct!("Table");
 create_value_like_struct!("Object");
+create_value_like_struct!("Uuid");
 
 /// Database index name
 pub type IndexName = Field;
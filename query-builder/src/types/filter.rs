@@ -597,6 +597,12 @@ impl Filter {
         }
     }
 
+    /// Adds bindings to the filter
+    pub(crate) fn with_bindings(mut self, bindings: BindingsList) -> Self {
+        self.bindings.extend(bindings);
+        self
+    }
+
     pub(crate) fn ___update_bindings(self, filter: &impl Parametric) -> Vec<Binding> {
         [self.bindings.as_slice(), filter.get_bindings().as_slice()].concat()
     }
@@ -35,6 +35,10 @@ pub struct User(sql::Idiom);
 #[derive(Debug, Clone)]
 pub struct Scope(sql::Idiom);
 
+/// Surreal access method
+#[derive(Debug, Clone)]
+pub struct Access(sql::Idiom);
+
 /// Surreal table
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Table(sql::Table);
@@ -100,6 +104,18 @@ impl From<&Table> for Table {
 /// A collection of tables
 pub struct Tables(Vec<Table>);
 
+impl From<Table> for Tables {
+    fn from(value: Table) -> Self {
+        Self(vec![value])
+    }
+}
+
+impl From<&str> for Tables {
+    fn from(value: &str) -> Self {
+        Self(vec![value.into()])
+    }
+}
+
 impl From<Vec<Table>> for Tables {
     fn from(value: Vec<Table>) -> Self {
         Self(value)
@@ -149,7 +165,7 @@ macro_rules! impl_new_for_all {
     };
 }
 
-impl_new_for_all!(Namespace, Database, Login, Token, User, Scope, Event, TableIndex);
+impl_new_for_all!(Namespace, Database, Login, Token, User, Scope, Access, Event, TableIndex);
 
 macro_rules! impl_display_for_all {
     ($($types_:ty),*) => {
@@ -186,7 +202,9 @@ macro_rules! impl_display_for_all {
     )*
     };
 }
-impl_display_for_all!(Namespace, Database, Login, Token, User, Scope, Table, Event, TableIndex);
+impl_display_for_all!(
+    Namespace, Database, Login, Token, User, Scope, Access, Table, Event, TableIndex
+);
 
 /// Wrapper around Surreal idiom. X suffix stands for extra.
 pub struct Idiomx(sql::Idiom);
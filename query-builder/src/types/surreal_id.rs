@@ -9,7 +9,9 @@ use std::{collections::HashMap, fmt::Display, marker::PhantomData, ops::Deref};
 use serde::{Deserialize, Serialize};
 use surrealdb::sql::{self, thing, Thing};
 
-use crate::{Erroneous, Model, SurrealOrmError};
+#[cfg(not(feature = "surrealdb-v2"))]
+use crate::functions::meta;
+use crate::{functions::record, Erroneous, Function, Model, SurrealOrmError};
 
 /// Wrapper around surrealdb::sql::Thing to extend its capabilities
 /// and provide a more ergonomic interface. This is used to create a statically
@@ -128,6 +130,30 @@ where
     pub fn to_raw(&self) -> String {
         self.to_thing().to_raw()
     }
+
+    /// Returns a `record::exists` expression checking that the record exists.
+    /// e.g `record::exists(user:oyelowo)`. Useful in ASSERTs and event WHEN clauses.
+    pub fn record_exists(&self) -> Function {
+        record::exists_fn(self.to_thing())
+    }
+
+    /// Returns an expression of the table of the id e.g `meta::tb(user:oyelowo)`,
+    /// or `record::tb(user:oyelowo)` with the `surrealdb-v2` feature.
+    pub fn table_expr(&self) -> Function {
+        #[cfg(feature = "surrealdb-v2")]
+        return record::tb_fn(self.to_thing());
+        #[cfg(not(feature = "surrealdb-v2"))]
+        return meta::tb_fn(self.to_thing());
+    }
+
+    /// Returns an expression of the identifier of the id e.g `meta::id(user:oyelowo)`,
+    /// or `record::id(user:oyelowo)` with the `surrealdb-v2` feature.
+    pub fn id_expr(&self) -> Function {
+        #[cfg(feature = "surrealdb-v2")]
+        return record::id_fn(self.to_thing());
+        #[cfg(not(feature = "surrealdb-v2"))]
+        return meta::id_fn(self.to_thing());
+    }
 }
 
 impl<T, Id> From<SurrealId<T, Id>> for sql::Thing
@@ -500,6 +526,7 @@ impl Model for TestUser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Buildable, ToRaw};
     use surrealdb::sql::Uuid;
 
     #[test]
@@ -557,6 +584,31 @@ mod tests {
         assert_eq!(id.to_string().len(), 47);
     }
 
+    #[test]
+    fn test_surreal_id_record_function_expressions() {
+        let id = TestUserStringId::new("oyelowo".into());
+        assert_eq!(
+            id.record_exists().to_raw().build(),
+            "record::exists(user:oyelowo)"
+        );
+    }
+
+    #[cfg(not(feature = "surrealdb-v2"))]
+    #[test]
+    fn test_surreal_id_meta_function_expressions() {
+        let id = TestUserStringId::new("oyelowo".into());
+        assert_eq!(id.table_expr().to_raw().build(), "meta::tb(user:oyelowo)");
+        assert_eq!(id.id_expr().to_raw().build(), "meta::id(user:oyelowo)");
+    }
+
+    #[cfg(feature = "surrealdb-v2")]
+    #[test]
+    fn test_surreal_id_record_table_and_id_expressions() {
+        let id = TestUserStringId::new("oyelowo".into());
+        assert_eq!(id.table_expr().to_raw().build(), "record::tb(user:oyelowo)");
+        assert_eq!(id.id_expr().to_raw().build(), "record::id(user:oyelowo)");
+    }
+
     #[test]
     fn test_surreal_id() {
         let id = TestUserNumberId::try_from("table:1").unwrap();
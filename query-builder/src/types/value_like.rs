@@ -10,7 +10,7 @@ use crate::{
         RelateStatement, SelectStatement, Subquery, UpdateStatement,
     },
     Alias, All, Binding, BindingsList, Buildable, Edge, Erroneous, ErrorList, Field, Filter,
-    Function, Model, Node, Operation, Param, Parametric, E, NONE, NULL,
+    Function, Model, Node, Operation, Param, Parametric, PatchOp, E, NONE, NULL,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use surrealdb::sql;
@@ -216,6 +216,16 @@ impl From<Function> for ValueLike {
     }
 }
 
+impl From<PatchOp> for ValueLike {
+    fn from(value: PatchOp) -> Self {
+        ValueLike {
+            string: value.build(),
+            bindings: value.get_bindings(),
+            errors: value.get_errors(),
+        }
+    }
+}
+
 impl<T: Into<sql::Value>> From<T> for ValueLike {
     fn from(value: T) -> Self {
         let value: sql::Value = value.into();
@@ -266,6 +276,16 @@ where
     }
 }
 
+#[cfg(feature = "surrealdb-v2")]
+impl<T> From<crate::statements::UpsertStatement<T>> for ValueLike
+where
+    T: Model + Serialize + DeserializeOwned,
+{
+    fn from(statement: crate::statements::UpsertStatement<T>) -> Self {
+        statement_to_value_like(statement)
+    }
+}
+
 impl<T> From<DeleteStatement<T>> for ValueLike
 where
     T: Model + Serialize + DeserializeOwned,